@@ -0,0 +1,191 @@
+//! Fills in a JSON instance's missing values from the schema's `default`
+//! keywords, for the `--apply-defaults` flag.
+//!
+//! This walks the schema and instance in lockstep rather than the compiled
+//! validator: for an object subschema, a declared property absent from the
+//! instance gets its subschema's `default` inserted (then recursion
+//! continues into that property, whether it was already present or just
+//! filled in); for an array, the `items` subschema's defaults are applied to
+//! every element. `$ref` is resolved (by local JSON Pointer against the root
+//! schema) before reading `properties`/`default` off a subschema, and
+//! `allOf` branches each contribute their own defaults the same way the
+//! subschema itself would; `oneOf`/`anyOf` are left alone since which branch
+//! applies isn't knowable without validating first.
+//!
+//! TODO: `$ref` resolution only understands a local `#/...` JSON Pointer
+//! against the root schema document — remote/bundled references would need
+//! the compiler's `Registry`, which isn't part of this checkout.
+
+use serde_json::Value;
+
+/// Returns `instance` with every declared-but-missing property (and array
+/// element) filled in from the schema's `default` keywords.
+pub(crate) fn apply_defaults(root_schema: &Value, instance: &Value) -> Value {
+    let mut enriched = instance.clone();
+    walk(root_schema, root_schema, &mut enriched);
+    enriched
+}
+
+/// The value to use when the instance itself is entirely absent: the
+/// top-level schema's own `default`, or `null` if it has none.
+pub(crate) fn default_for_absent_instance(root_schema: &Value) -> Value {
+    resolve(root_schema, root_schema)
+        .and_then(|schema| schema.get("default"))
+        .cloned()
+        .unwrap_or(Value::Null)
+}
+
+fn walk(root: &Value, schema: &Value, instance: &mut Value) {
+    let Some(schema) = resolve(root, schema) else {
+        return;
+    };
+    let Value::Object(schema) = schema else {
+        return;
+    };
+
+    if let Some(Value::Array(branches)) = schema.get("allOf") {
+        for branch in branches {
+            walk(root, branch, instance);
+        }
+    }
+
+    match instance {
+        Value::Object(instance) => {
+            if let Some(Value::Object(properties)) = schema.get("properties") {
+                for (key, property_schema) in properties {
+                    if !instance.contains_key(key) {
+                        if let Some(default) = resolve(root, property_schema)
+                            .and_then(|resolved| resolved.get("default"))
+                        {
+                            instance.insert(key.clone(), default.clone());
+                        }
+                    }
+                    if let Some(value) = instance.get_mut(key) {
+                        walk(root, property_schema, value);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(items_schema) = schema.get("items") {
+                if items_schema.is_object() {
+                    for item in items {
+                        walk(root, items_schema, item);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolves a single local `$ref` (a `#/...` JSON Pointer into `root`), if
+/// `schema` has one; otherwise returns `schema` unchanged.
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> Option<&'a Value> {
+    let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+        return Some(schema);
+    };
+    let pointer = reference.strip_prefix('#')?;
+    root.pointer(pointer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply_defaults, default_for_absent_instance};
+    use serde_json::json;
+
+    #[test]
+    fn fills_in_a_missing_property_default() {
+        let schema = json!({
+            "properties": {
+                "name": {"type": "string", "default": "anonymous"},
+                "age": {"type": "integer"},
+            },
+        });
+        let instance = json!({"age": 30});
+        assert_eq!(
+            apply_defaults(&schema, &instance),
+            json!({"age": 30, "name": "anonymous"})
+        );
+    }
+
+    #[test]
+    fn does_not_overwrite_a_present_value() {
+        let schema = json!({"properties": {"name": {"default": "anonymous"}}});
+        let instance = json!({"name": "Alice"});
+        assert_eq!(apply_defaults(&schema, &instance), json!({"name": "Alice"}));
+    }
+
+    #[test]
+    fn recurses_into_nested_objects_and_present_properties() {
+        let schema = json!({
+            "properties": {
+                "address": {
+                    "properties": {"city": {"default": "Unknown"}},
+                },
+            },
+        });
+        let instance = json!({"address": {}});
+        assert_eq!(
+            apply_defaults(&schema, &instance),
+            json!({"address": {"city": "Unknown"}})
+        );
+    }
+
+    #[test]
+    fn applies_items_schema_default_to_every_array_element() {
+        let schema = json!({
+            "properties": {
+                "tags": {
+                    "items": {"properties": {"active": {"default": true}}},
+                },
+            },
+        });
+        let instance = json!({"tags": [{}, {"active": false}]});
+        assert_eq!(
+            apply_defaults(&schema, &instance),
+            json!({"tags": [{"active": true}, {"active": false}]})
+        );
+    }
+
+    #[test]
+    fn resolves_ref_before_reading_default() {
+        let schema = json!({
+            "$defs": {"named": {"properties": {"name": {"default": "anonymous"}}}},
+            "$ref": "#/$defs/named",
+        });
+        let instance = json!({});
+        assert_eq!(apply_defaults(&schema, &instance), json!({"name": "anonymous"}));
+    }
+
+    #[test]
+    fn merges_defaults_from_all_of_branches() {
+        let schema = json!({
+            "allOf": [
+                {"properties": {"a": {"default": 1}}},
+                {"properties": {"b": {"default": 2}}},
+            ],
+        });
+        let instance = json!({});
+        assert_eq!(apply_defaults(&schema, &instance), json!({"a": 1, "b": 2}));
+    }
+
+    #[test]
+    fn leaves_one_of_and_any_of_branches_untouched() {
+        let schema = json!({
+            "oneOf": [
+                {"properties": {"a": {"default": 1}}},
+                {"properties": {"b": {"default": 2}}},
+            ],
+        });
+        let instance = json!({});
+        assert_eq!(apply_defaults(&schema, &instance), json!({}));
+    }
+
+    #[test]
+    fn top_level_default_applies_only_when_instance_entirely_absent() {
+        let schema = json!({"default": {"name": "anonymous"}});
+        assert_eq!(default_for_absent_instance(&schema), json!({"name": "anonymous"}));
+        assert_eq!(apply_defaults(&schema, &json!({})), json!({}));
+    }
+}
@@ -0,0 +1,173 @@
+//! User-defined `format` assertions loaded from `--format-def`/`--format-config`,
+//! for names the compiler doesn't know about (e.g. an internal ID shape).
+//!
+//! This walks the schema/instance in lockstep rather than going through the
+//! compiler's format registry — like `apply_defaults`, it only understands
+//! local `#/...` `$ref`s against the root schema document.
+//!
+//! TODO: only the top-level `"valid"` flag is corrected for `flag`/`list`/
+//! `hierarchical` output; a custom-format failure doesn't get its own entry
+//! in those payloads' error/detail arrays, since building one compatible
+//! with the compiled evaluation's own shape would need its internals.
+
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+
+use regex::Regex;
+use serde_json::Value;
+
+/// Compiled `NAME -> REGEX` custom format definitions.
+pub(crate) struct CustomFormats(HashMap<String, Regex>);
+
+impl CustomFormats {
+    /// Loads definitions from an optional `--format-config <FILE>` (a JSON
+    /// object mapping format name to either a regex string or `{"pattern":
+    /// ...}`) and then layers `--format-def NAME=REGEX` entries on top, so a
+    /// repeated name on the command line overrides the config file.
+    pub(crate) fn load(
+        format_defs: &[String],
+        config_path: Option<&Path>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut formats = HashMap::new();
+
+        if let Some(path) = config_path {
+            let file = File::open(path)?;
+            let config: Value = serde_json::from_reader(BufReader::new(file))?;
+            let Value::Object(entries) = config else {
+                return Err(format!(
+                    "{} must be a JSON object mapping format name to regex",
+                    path.display()
+                )
+                .into());
+            };
+            for (name, value) in entries {
+                let pattern = match &value {
+                    Value::String(pattern) => pattern.clone(),
+                    Value::Object(entry) => entry
+                        .get("pattern")
+                        .and_then(Value::as_str)
+                        .ok_or_else(|| format!("format \"{name}\" is missing a \"pattern\" string"))?
+                        .to_string(),
+                    _ => {
+                        return Err(format!(
+                            "format \"{name}\" must be a regex string or an object with a \"pattern\" field"
+                        )
+                        .into())
+                    }
+                };
+                let regex = Regex::new(&pattern)
+                    .map_err(|error| format!("invalid regex for format \"{name}\": {error}"))?;
+                formats.insert(name, regex);
+            }
+        }
+
+        for raw in format_defs {
+            let (name, regex) = Self::parse_def(raw)?;
+            formats.insert(name, regex);
+        }
+
+        Ok(Self(formats))
+    }
+
+    /// Parses a single `--format-def` argument in `NAME=REGEX` form.
+    pub(crate) fn parse_def(raw: &str) -> Result<(String, Regex), String> {
+        let (name, pattern) = raw
+            .split_once('=')
+            .ok_or_else(|| format!("'{raw}' is not in NAME=REGEX form"))?;
+        if name.is_empty() {
+            return Err("NAME must not be empty".to_string());
+        }
+        let regex = Regex::new(pattern)
+            .map_err(|error| format!("invalid regex for format \"{name}\": {error}"))?;
+        Ok((name.to_string(), regex))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Checks `instance` against every custom `format` keyword reachable
+    /// from `root`, returning one message per string that fails its regex.
+    pub(crate) fn check(&self, root: &Value, instance: &Value) -> Vec<String> {
+        let mut errors = Vec::new();
+        self.walk(root, root, instance, "", &mut errors);
+        errors
+    }
+
+    fn walk(&self, root: &Value, schema: &Value, instance: &Value, pointer: &str, errors: &mut Vec<String>) {
+        let Some(schema) = resolve(root, schema) else {
+            return;
+        };
+        let Value::Object(schema) = schema else {
+            return;
+        };
+
+        if let Some(format_name) = schema.get("format").and_then(Value::as_str) {
+            if let (Some(regex), Value::String(text)) = (self.0.get(format_name), instance) {
+                if !regex.is_match(text) {
+                    let at = if pointer.is_empty() { "/" } else { pointer };
+                    errors.push(format!(
+                        "{text:?} does not match format \"{format_name}\" at {at}"
+                    ));
+                }
+            }
+        }
+
+        match instance {
+            Value::Object(instance) => {
+                if let Some(Value::Object(properties)) = schema.get("properties") {
+                    for (key, property_schema) in properties {
+                        if let Some(value) = instance.get(key) {
+                            self.walk(root, property_schema, value, &format!("{pointer}/{key}"), errors);
+                        }
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(items_schema) = schema.get("items") {
+                    if items_schema.is_object() {
+                        for (index, item) in items.iter().enumerate() {
+                            self.walk(root, items_schema, item, &format!("{pointer}/{index}"), errors);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolves a single local `$ref` (a `#/...` JSON Pointer into `root`), if
+/// `schema` has one; otherwise returns `schema` unchanged.
+fn resolve<'a>(root: &'a Value, schema: &'a Value) -> Option<&'a Value> {
+    let Some(reference) = schema.get("$ref").and_then(Value::as_str) else {
+        return Some(schema);
+    };
+    let pointer = reference.strip_prefix('#')?;
+    root.pointer(pointer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CustomFormats;
+    use serde_json::json;
+
+    #[test]
+    fn format_def_overrides_are_parsed_and_matched() {
+        let formats = CustomFormats::load(&["ticket=^T-\\d+$".to_string()], None).unwrap();
+        let schema = json!({"properties": {"id": {"type": "string", "format": "ticket"}}});
+        assert!(formats.check(&schema, &json!({"id": "T-123"})).is_empty());
+        assert_eq!(formats.check(&schema, &json!({"id": "nope"})).len(), 1);
+    }
+
+    #[test]
+    fn unregistered_format_names_are_left_alone() {
+        let formats = CustomFormats::load(&[], None).unwrap();
+        let schema = json!({"format": "email"});
+        assert!(formats.check(&schema, &json!("not-an-email")).is_empty());
+    }
+
+    #[test]
+    fn rejects_a_malformed_format_def() {
+        assert!(CustomFormats::load(&["no-equals-sign".to_string()], None).is_err());
+    }
+}
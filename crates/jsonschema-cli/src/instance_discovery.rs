@@ -0,0 +1,136 @@
+//! Expands `--instance` arguments that name a directory or a glob pattern
+//! into the concrete list of files to validate, so a single argument can
+//! cover a whole batch of instances instead of one file per argument.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Resolves each of `paths` into one or more concrete instance files:
+/// - a directory is walked recursively, collecting every `*.json` file;
+/// - a path containing `*` or `?` that doesn't exist literally is expanded
+///   as a glob against its parent directory (character classes like `[abc]`
+///   aren't supported);
+/// - anything else is kept as-is, so a plain file path behaves exactly as
+///   before.
+///
+/// Each input's expansion is sorted for deterministic output ordering.
+pub(crate) fn discover_instances(paths: &[PathBuf]) -> std::io::Result<Vec<PathBuf>> {
+    let mut discovered = Vec::new();
+    for path in paths {
+        if path.is_dir() {
+            let mut files = Vec::new();
+            walk_json_files(path, &mut files)?;
+            files.sort();
+            discovered.extend(files);
+        } else if !path.exists() && is_glob_pattern(path) {
+            discovered.extend(expand_glob(path)?);
+        } else {
+            discovered.push(path.clone());
+        }
+    }
+    Ok(discovered)
+}
+
+fn walk_json_files(dir: &Path, out: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            walk_json_files(&path, out)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn is_glob_pattern(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?'])
+}
+
+fn expand_glob(pattern: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let pattern_str = pattern.to_string_lossy();
+    let (dir, file_pattern) = match pattern_str.rfind('/') {
+        Some(index) => (Path::new(&pattern_str[..index]), &pattern_str[index + 1..]),
+        None => (Path::new("."), pattern_str.as_ref()),
+    };
+
+    let mut matches = Vec::new();
+    if dir.is_dir() {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            if glob_match(file_pattern, &entry.file_name().to_string_lossy()) {
+                matches.push(entry.path());
+            }
+        }
+    }
+    matches.sort();
+    Ok(matches)
+}
+
+/// A minimal single-segment glob matcher supporting `*` (any run of
+/// characters, including none) and `?` (exactly one character); instance
+/// globs only ever need to match file names within a single directory, so
+/// there's no path-separator or character-class support to get right.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    fn matches_from(pattern: &[u8], name: &[u8]) -> bool {
+        match pattern.first() {
+            None => name.is_empty(),
+            Some(b'*') => (0..=name.len()).any(|i| matches_from(&pattern[1..], &name[i..])),
+            Some(b'?') => !name.is_empty() && matches_from(&pattern[1..], &name[1..]),
+            Some(&c) => name.first() == Some(&c) && matches_from(&pattern[1..], &name[1..]),
+        }
+    }
+    matches_from(pattern.as_bytes(), name.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{discover_instances, glob_match};
+    use std::fs;
+
+    #[test]
+    fn exact_match_requires_the_whole_name() {
+        assert!(glob_match("*.json", "a.json"));
+        assert!(!glob_match("*.json", "a.json.bak"));
+        assert!(glob_match("data?.json", "data1.json"));
+        assert!(!glob_match("data?.json", "data12.json"));
+    }
+
+    #[test]
+    fn directory_argument_recursively_collects_json_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.json"), "1").unwrap();
+        fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+        let nested = dir.path().join("nested");
+        fs::create_dir(&nested).unwrap();
+        fs::write(nested.join("b.json"), "2").unwrap();
+
+        let discovered = discover_instances(&[dir.path().to_path_buf()]).unwrap();
+        assert_eq!(discovered.len(), 2);
+        assert!(discovered.iter().all(|p| p.extension().unwrap() == "json"));
+    }
+
+    #[test]
+    fn glob_argument_expands_to_matching_files() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("valid1.json"), "1").unwrap();
+        fs::write(dir.path().join("valid2.json"), "2").unwrap();
+        fs::write(dir.path().join("other.txt"), "x").unwrap();
+
+        let pattern = dir.path().join("valid*.json");
+        let discovered = discover_instances(&[pattern]).unwrap();
+        assert_eq!(discovered.len(), 2);
+    }
+
+    #[test]
+    fn plain_file_argument_is_kept_as_is() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("instance.json");
+        fs::write(&file, "1").unwrap();
+
+        let discovered = discover_instances(&[file.clone()]).unwrap();
+        assert_eq!(discovered, vec![file]);
+    }
+}
@@ -1,26 +1,88 @@
 #![allow(clippy::print_stdout)]
+mod apply_defaults;
+mod custom_formats;
+mod instance_discovery;
+mod retriever;
+mod schema_routing;
+
 use std::{
     fs::File,
     io::BufReader,
     path::{Path, PathBuf},
     process::ExitCode,
+    time::Duration,
 };
 
-use clap::{ArgAction, Parser, ValueEnum};
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
+use clap_complete::Shell;
 use percent_encoding::{percent_encode, AsciiSet, CONTROLS};
-use serde_json::json;
+use serde_json::{json, Value};
+
+use apply_defaults::{apply_defaults, default_for_absent_instance};
+use custom_formats::CustomFormats;
+use instance_discovery::discover_instances;
+use retriever::{default_cache_dir, is_http_url, CachingRetriever, HttpConfig, MappedRetriever, UriMapping};
+use schema_routing::SchemaRouter;
+
+fn parse_timeout_secs(raw: &str) -> Result<f64, String> {
+    let value: f64 = raw
+        .parse()
+        .map_err(|_| format!("'{raw}' is not a valid number"))?;
+    if !value.is_finite() || value < 0.0 {
+        return Err(format!("'{raw}' must be a non-negative finite number"));
+    }
+    Ok(value)
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate shell completion scripts for the `jsonschema` CLI.
+    Completions {
+        /// The shell to generate completions for.
+        shell: Shell,
+    },
+    /// Report the crate version and validation capabilities.
+    Version {
+        /// Print the report as JSON instead of plain text.
+        #[arg(long = "json")]
+        json: bool,
+    },
+}
 
 #[derive(Parser)]
 #[command(name = "jsonschema")]
 struct Cli {
-    /// A path to a JSON instance (i.e. filename.json) to validate (may be specified multiple times).
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// A path to a JSON instance (i.e. filename.json) to validate (may be specified multiple
+    /// times). A directory is walked recursively for `*.json` files, and a pattern containing
+    /// `*` or `?` is expanded as a glob, both against the compiled validator.
     #[arg(short = 'i', long = "instance")]
     instances: Option<Vec<PathBuf>>,
 
-    /// The JSON Schema to validate with (i.e. schema.json).
-    #[arg(value_parser, required_unless_present("version"))]
+    /// The JSON Schema to validate with (i.e. schema.json). May be omitted when `--schema-dir`
+    /// is given, in which case each instance names its own schema instead.
+    #[arg(value_parser, required_unless_present_any(["version", "command", "schema_dir"]))]
     schema: Option<PathBuf>,
 
+    /// Route each instance to its own schema within this directory, read from `--route-field`,
+    /// instead of validating every instance against one fixed `schema` argument. Requires
+    /// `--instance` or `--stdin` to supply the instances to route.
+    #[arg(
+        long = "schema-dir",
+        help = "Validate each instance against a schema selected from this directory"
+    )]
+    schema_dir: Option<PathBuf>,
+
+    /// The instance field naming the routed schema's path, relative to `--schema-dir`.
+    #[arg(
+        long = "route-field",
+        default_value = "$schema",
+        help = "Instance field naming the routed schema path (e.g. \"$schema\" or \"$id\")"
+    )]
+    route_field: String,
+
     /// Which JSON Schema draft to enforce.
     #[arg(
         short = 'd',
@@ -57,13 +119,103 @@ struct Cli {
     )]
     output: Output,
 
-    /// Show program's version number and exit.
+    /// Show the crate version, supported drafts and compiled-in features, then exit.
     #[arg(short = 'v', long = "version")]
     version: bool,
 
     /// Only output validation failures, suppress successful validations.
     #[arg(long = "errors-only", help = "Only show validation errors")]
     errors_only: bool,
+
+    /// Emit a single aggregated JSON summary after validating all instances.
+    #[arg(
+        long = "summary",
+        help = "Emit a combined summary object with total/valid/invalid counts"
+    )]
+    summary: bool,
+
+    /// Before validating (or with no instance at all), also emit an enriched copy of each
+    /// instance to stdout with missing values filled in from the schema's `default` keywords.
+    #[arg(
+        long = "apply-defaults",
+        help = "Emit each instance enriched with schema `default` values"
+    )]
+    apply_defaults: bool,
+
+    /// Directory used to cache schemas and `$ref`s fetched over HTTP(S).
+    #[arg(
+        long = "cache-dir",
+        help = "Directory for caching fetched remote schemas (defaults to an OS cache directory)"
+    )]
+    cache_dir: Option<PathBuf>,
+
+    /// Forbid network access and only resolve remote references from the cache.
+    #[arg(long = "offline", help = "Only use cached documents, never hit the network")]
+    offline: bool,
+
+    /// Timeout, in seconds, for HTTP(S) requests.
+    #[arg(
+        long = "timeout",
+        value_parser = parse_timeout_secs,
+        help = "Timeout in seconds for HTTP(S) requests"
+    )]
+    timeout: Option<f64>,
+
+    /// Timeout, in seconds, for establishing HTTP(S) connections.
+    #[arg(
+        long = "connect-timeout",
+        value_parser = parse_timeout_secs,
+        help = "Timeout in seconds for establishing HTTP(S) connections"
+    )]
+    connect_timeout: Option<f64>,
+
+    /// Disable TLS certificate verification for HTTP(S) requests.
+    #[arg(
+        short = 'k',
+        long = "insecure",
+        help = "Disable TLS certificate verification"
+    )]
+    insecure: bool,
+
+    /// Path to a custom CA certificate bundle (PEM) for HTTP(S) requests.
+    #[arg(long = "cacert", help = "Path to a CA certificate bundle (PEM)")]
+    cacert: Option<PathBuf>,
+
+    /// Intercept `$ref` resolution for URIs starting with PREFIX, loading the rest of the
+    /// path from LOCALDIR instead of fetching it over the network (may be specified multiple
+    /// times). Combine with `--offline` to hard-fail any reference not covered by a mapping.
+    #[arg(
+        long = "map-uri",
+        value_parser = UriMapping::parse,
+        help = "Resolve $refs under PREFIX from LOCALDIR instead of the network (PREFIX=LOCALDIR)"
+    )]
+    map_uri: Option<Vec<UriMapping>>,
+
+    /// Register a custom `format` assertion as NAME=REGEX (may be specified multiple times),
+    /// supplementing the built-ins like `date-time`, `ipv4`, and `ipv6`. Only takes effect
+    /// with `--assert-format`.
+    #[arg(
+        long = "format-def",
+        help = "Register a custom format as NAME=REGEX (requires --assert-format)"
+    )]
+    format_def: Vec<String>,
+
+    /// Load custom `format` assertions from a JSON file mapping format name to either a regex
+    /// string or `{"pattern": ...}`. A name also given via `--format-def` overrides the entry
+    /// loaded from this file.
+    #[arg(
+        long = "format-config",
+        help = "Load custom format definitions from a JSON config file (requires --assert-format)"
+    )]
+    format_config: Option<PathBuf>,
+
+    /// Read instances as newline-delimited JSON from stdin instead of files.
+    #[arg(
+        long = "stdin",
+        conflicts_with = "instances",
+        help = "Validate newline-delimited JSON instances read from stdin"
+    )]
+    stdin: bool,
 }
 
 #[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
@@ -72,6 +224,8 @@ enum Output {
     Flag,
     List,
     Hierarchical,
+    Summary,
+    Sarif,
 }
 
 impl Output {
@@ -81,6 +235,8 @@ impl Output {
             Output::Flag => "flag",
             Output::List => "list",
             Output::Hierarchical => "hierarchical",
+            Output::Summary => "summary",
+            Output::Sarif => "sarif",
         }
     }
 }
@@ -111,6 +267,51 @@ impl From<Draft> for jsonschema::Draft {
     }
 }
 
+impl Draft {
+    /// All drafts the CLI can be asked to enforce via `--draft`, oldest first.
+    const ALL: [Draft; 5] = [
+        Draft::Draft4,
+        Draft::Draft6,
+        Draft::Draft7,
+        Draft::Draft201909,
+        Draft::Draft202012,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Draft::Draft4 => "4",
+            Draft::Draft6 => "6",
+            Draft::Draft7 => "7",
+            Draft::Draft201909 => "2019-09",
+            Draft::Draft202012 => "2020-12",
+        }
+    }
+}
+
+/// Print the crate version and the set of supported drafts/features, so
+/// scripts can check at runtime whether a draft or feature is available
+/// before invoking validation.
+fn print_version_report(json: bool) {
+    let drafts: Vec<&str> = Draft::ALL.iter().map(|d| d.as_str()).collect();
+    let default_draft = Draft::Draft202012.as_str();
+    let features = ["format-assertions"];
+
+    if json {
+        let report = json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "drafts": drafts,
+            "default_draft": default_draft,
+            "features": features,
+        });
+        println!("{}", serde_json::to_string(&report).expect("report is always serializable"));
+    } else {
+        println!(concat!("Version: ", env!("CARGO_PKG_VERSION")));
+        println!("Supported drafts: {}", drafts.join(", "));
+        println!("Default draft: {default_draft}");
+        println!("Features: {}", features.join(", "));
+    }
+}
+
 fn read_json(
     path: &Path,
 ) -> Result<serde_json::Result<serde_json::Value>, Box<dyn std::error::Error>> {
@@ -181,117 +382,927 @@ fn path_to_uri(path: &std::path::Path) -> String {
     result
 }
 
-fn validate_instances(
-    instances: &[PathBuf],
+/// Per-instance summary entry aggregated into the combined `--summary` report.
+struct SummaryEntry {
+    instance: String,
+    valid: bool,
+    errors: Vec<String>,
+}
+
+fn print_summary(schema_path: &Path, entries: &[SummaryEntry]) -> Result<(), serde_json::Error> {
+    let valid_count = entries.iter().filter(|e| e.valid).count();
+    let invalid_count = entries.len() - valid_count;
+    let report = json!({
+        "schema": schema_path.to_string_lossy(),
+        "total": entries.len(),
+        "valid": valid_count,
+        "invalid": invalid_count,
+        "instances": entries.iter().map(|e| json!({
+            "instance": &e.instance,
+            "valid": e.valid,
+            "errors": &e.errors,
+        })).collect::<Vec<_>>(),
+    });
+    println!("{}", serde_json::to_string(&report)?);
+    Ok(())
+}
+
+/// A single validation failure, ready to be rendered as a SARIF `result`.
+struct SarifFailure {
+    rule_id: String,
+    message: String,
+    artifact_uri: String,
+    instance_pointer: String,
+}
+
+fn sarif_result(failure: &SarifFailure) -> Value {
+    json!({
+        "ruleId": failure.rule_id,
+        "level": "error",
+        "message": { "text": failure.message },
+        "locations": [
+            {
+                "physicalLocation": {
+                    "artifactLocation": { "uri": failure.artifact_uri },
+                    "region": {},
+                },
+                "logicalLocations": [
+                    { "fullyQualifiedName": failure.instance_pointer },
+                ],
+            },
+        ],
+    })
+}
+
+/// Build a SARIF 2.1.0 log with a single run covering all validated instances.
+fn print_sarif(
+    rule_ids: &std::collections::BTreeSet<String>,
+    failures: &[SarifFailure],
+) -> Result<(), serde_json::Error> {
+    let rules: Vec<Value> = rule_ids.iter().map(|id| json!({ "id": id })).collect();
+    let results: Vec<Value> = failures.iter().map(sarif_result).collect();
+    let log = json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [
+            {
+                "tool": {
+                    "driver": {
+                        "name": "jsonschema-cli",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            },
+        ],
+    });
+    println!("{}", serde_json::to_string(&log)?);
+    Ok(())
+}
+
+/// Schema and builder options prepared for compilation, shared by the
+/// file/stdin instance-validation entry points.
+struct PreparedSchema {
+    schema_json: Value,
+    options: jsonschema::ValidationOptions,
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prepare_schema(
     schema_path: &Path,
     draft: Option<Draft>,
     assert_format: Option<bool>,
-    output: Output,
-    errors_only: bool,
-) -> Result<bool, Box<dyn std::error::Error>> {
-    let mut success = true;
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+    map_uris: Vec<UriMapping>,
+) -> Result<PreparedSchema, Box<dyn std::error::Error>> {
+    let schema_display = schema_path.to_string_lossy().to_string();
+    if let Some(ca_cert) = &http.ca_cert {
+        std::fs::metadata(ca_cert).map_err(|source| retriever::RetrieverError::CaCert {
+            path: ca_cert.to_string_lossy().into_owned(),
+            source,
+        })?;
+    }
+    let retriever = CachingRetriever::new(cache_dir, offline, http);
 
-    let schema_json = read_json(schema_path)??;
-    let base_uri = path_to_uri(schema_path);
+    let (schema_json, base_uri) = if is_http_url(&schema_display) {
+        let schema_json = retriever::fetch_standalone(&retriever, &schema_display)?;
+        (schema_json, schema_display.clone())
+    } else {
+        (read_json(schema_path)??, path_to_uri(schema_path))
+    };
     let base_uri = referencing::uri::from_str(&base_uri)?;
-    let mut options = jsonschema::options().with_base_uri(base_uri);
+    let retriever = MappedRetriever::new(map_uris, retriever);
+    let mut options = jsonschema::options()
+        .with_base_uri(base_uri)
+        .with_retriever(retriever);
     if let Some(draft) = draft {
         options = options.with_draft(draft.into());
     }
     if let Some(assert_format) = assert_format {
         options = options.should_validate_formats(assert_format);
     }
-    match options.build(&schema_json) {
+    Ok(PreparedSchema {
+        schema_json,
+        options,
+    })
+}
+
+/// Runs the `--apply-defaults` mode: emits each instance (or, with none
+/// given, the schema's own top-level default) enriched with missing values
+/// filled in from the schema's `default` keywords.
+#[allow(clippy::too_many_arguments)]
+fn run_apply_defaults(
+    schema_path: &Path,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    instances: Option<&[PathBuf]>,
+    stdin: bool,
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+    map_uris: Vec<UriMapping>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let prepared = prepare_schema(schema_path, draft, assert_format, cache_dir, offline, http, map_uris)?;
+    let schema_json = prepared.schema_json;
+
+    if stdin {
+        use std::io::BufRead;
+        let stdin = std::io::stdin();
+        for line in stdin.lock().lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let instance_json: Value = serde_json::from_str(&line)?;
+            println!(
+                "{}",
+                serde_json::to_string(&apply_defaults(&schema_json, &instance_json))?
+            );
+        }
+    } else if let Some(instances) = instances {
+        for instance in discover_instances(instances)? {
+            let instance_json = read_json(&instance)??;
+            println!(
+                "{}",
+                serde_json::to_string(&apply_defaults(&schema_json, &instance_json))?
+            );
+        }
+    } else {
+        println!(
+            "{}",
+            serde_json::to_string(&default_for_absent_instance(&schema_json))?
+        );
+    }
+    Ok(())
+}
+
+/// Describe why a schema failed to compile, respecting the selected
+/// `--output` format the same way instance-validation failures do.
+///
+/// When the schema also fails meta-schema validation, that's surfaced as a
+/// distinct "does not conform to its meta-schema" diagnostic rather than the
+/// more opaque compile-time error alone.
+fn describe_schema_build_error(
+    schema_json: &Value,
+    error: &jsonschema::ValidationError<'_>,
+) -> String {
+    match jsonschema::meta::validate(schema_json) {
+        Err(meta_error) => {
+            format!("{error} (schema does not conform to its meta-schema: {meta_error})")
+        }
+        Ok(()) => error.to_string(),
+    }
+}
+
+fn print_schema_build_error(
+    schema_display: &str,
+    output: Output,
+    message: &str,
+) -> Result<(), serde_json::Error> {
+    if matches!(output, Output::Flag | Output::List | Output::Hierarchical) {
+        let record = json!({
+            "output": output.as_str(),
+            "schema": schema_display,
+            "payload": { "valid": false },
+        });
+        println!("{}", serde_json::to_string(&record)?);
+    } else {
+        println!("Schema is invalid. Error: {message}");
+    }
+    Ok(())
+}
+
+/// Validate the schema itself when no `--instance`/`--stdin` input is given,
+/// reporting whether it compiles rather than validating any instance against it.
+#[allow(clippy::too_many_arguments)]
+fn validate_schema_only(
+    schema_path: &Path,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    output: Output,
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+    map_uris: Vec<UriMapping>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let prepared = prepare_schema(schema_path, draft, assert_format, cache_dir, offline, http, map_uris)?;
+    let schema_display = schema_path.to_string_lossy().to_string();
+
+    match prepared.options.build(&prepared.schema_json) {
+        Ok(_) => {
+            if matches!(output, Output::Flag | Output::List | Output::Hierarchical) {
+                let record = json!({
+                    "output": output.as_str(),
+                    "schema": &schema_display,
+                    "payload": { "valid": true },
+                });
+                println!("{}", serde_json::to_string(&record)?);
+            } else {
+                println!("Schema is valid");
+            }
+            Ok(true)
+        }
+        Err(error) => {
+            let message = describe_schema_build_error(&prepared.schema_json, &error);
+            // A schema that conforms to its meta-schema but still fails to
+            // build (e.g. an invalid `$ref` target) has no structured record
+            // to emit, so it is reported as a plain error.
+            if matches!(output, Output::Flag | Output::List | Output::Hierarchical)
+                && jsonschema::meta::validate(&prepared.schema_json).is_ok()
+            {
+                return Err(message.into());
+            }
+            print_schema_build_error(&schema_display, output, &message)?;
+            Ok(false)
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn validate_instances(
+    instances: &[PathBuf],
+    schema_path: &Path,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    output: Output,
+    errors_only: bool,
+    summary: bool,
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+    map_uris: Vec<UriMapping>,
+    custom_formats: &CustomFormats,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    let mut success = true;
+    let mut summary_entries = Vec::with_capacity(instances.len());
+    let check_custom_formats = assert_format == Some(true) && !custom_formats.is_empty();
+
+    let prepared = prepare_schema(schema_path, draft, assert_format, cache_dir, offline, http, map_uris)?;
+    let schema_json = prepared.schema_json;
+    let schema_display = schema_path.to_string_lossy().to_string();
+
+    match prepared.options.build(&schema_json) {
         Ok(validator) => {
             if matches!(output, Output::Text) {
                 for instance in instances {
                     let instance_json = read_json(instance)??;
-                    let mut errors = validator.iter_errors(&instance_json);
+                    let mut error_messages: Vec<String> = validator
+                        .iter_errors(&instance_json)
+                        .map(|error| error.to_string())
+                        .collect();
+                    if check_custom_formats {
+                        error_messages.extend(custom_formats.check(&schema_json, &instance_json));
+                    }
                     let filename = instance.to_string_lossy();
-                    if let Some(first) = errors.next() {
+                    if !error_messages.is_empty() {
                         success = false;
-                        println!("{filename} - INVALID. Errors:");
-                        println!("1. {first}");
-                        for (i, error) in errors.enumerate() {
-                            println!("{}. {error}", i + 2);
+                        if !summary {
+                            println!("{filename} - INVALID. Errors:");
+                            for (i, error) in error_messages.iter().enumerate() {
+                                println!("{}. {error}", i + 1);
+                            }
                         }
-                    } else if !errors_only {
+                    } else if !errors_only && !summary {
                         println!("{filename} - VALID");
                     }
+                    summary_entries.push(SummaryEntry {
+                        instance: filename.into_owned(),
+                        valid: error_messages.is_empty(),
+                        errors: error_messages,
+                    });
+                }
+            } else if matches!(output, Output::Sarif) {
+                let mut rule_ids = std::collections::BTreeSet::new();
+                let mut failures = Vec::new();
+                for instance in instances {
+                    let instance_json = read_json(instance)??;
+                    let artifact_uri = path_to_uri(instance);
+                    for error in validator.iter_errors(&instance_json) {
+                        success = false;
+                        let rule_id = error.kind().keyword().to_string();
+                        rule_ids.insert(rule_id.clone());
+                        failures.push(SarifFailure {
+                            rule_id,
+                            message: error.to_string(),
+                            artifact_uri: artifact_uri.clone(),
+                            instance_pointer: error.instance_path().as_str().to_string(),
+                        });
+                    }
+                    if check_custom_formats {
+                        for message in custom_formats.check(&schema_json, &instance_json) {
+                            success = false;
+                            let rule_id = "custom-format".to_string();
+                            rule_ids.insert(rule_id.clone());
+                            failures.push(SarifFailure {
+                                rule_id,
+                                message,
+                                artifact_uri: artifact_uri.clone(),
+                                instance_pointer: String::new(),
+                            });
+                        }
+                    }
                 }
+                print_sarif(&rule_ids, &failures)?;
             } else {
-                let schema_display = schema_path.to_string_lossy().to_string();
                 let output_format = output.as_str();
                 for instance in instances {
                     let instance_json = read_json(instance)??;
                     let evaluation = validator.evaluate(&instance_json);
                     let flag_output = evaluation.flag();
+                    let list_value = serde_json::to_value(evaluation.list())?;
+                    let mut error_messages: Vec<String> = validator
+                        .iter_errors(&instance_json)
+                        .map(|error| error.to_string())
+                        .collect();
+                    if check_custom_formats {
+                        error_messages.extend(custom_formats.check(&schema_json, &instance_json));
+                    }
+                    let valid = flag_output.valid && error_messages.is_empty();
 
-                    // Skip valid instances if errors_only is enabled
-                    if errors_only && flag_output.valid {
-                        continue;
+                    let instance_display = instance.to_string_lossy();
+
+                    if !matches!(output, Output::Summary) {
+                        // Skip valid instances if errors_only is enabled
+                        if !(errors_only && valid) {
+                            let mut payload = match output {
+                                Output::Text | Output::Summary | Output::Sarif => {
+                                    unreachable!("handled above")
+                                }
+                                Output::Flag => serde_json::to_value(flag_output)?,
+                                Output::List => list_value.clone(),
+                                Output::Hierarchical => {
+                                    serde_json::to_value(evaluation.hierarchical())?
+                                }
+                            };
+                            if !valid {
+                                if let Value::Object(ref mut fields) = payload {
+                                    fields.insert("valid".to_string(), json!(false));
+                                }
+                            }
+                            let record = json!({
+                                "output": output_format,
+                                "schema": &schema_display,
+                                "instance": &instance_display,
+                                "payload": payload,
+                            });
+                            println!("{}", serde_json::to_string(&record)?);
+                        }
                     }
 
-                    let payload = match output {
-                        Output::Text => unreachable!("handled above"),
-                        Output::Flag => serde_json::to_value(flag_output)?,
-                        Output::List => serde_json::to_value(evaluation.list())?,
-                        Output::Hierarchical => serde_json::to_value(evaluation.hierarchical())?,
-                    };
+                    if !valid {
+                        success = false;
+                    }
+                    summary_entries.push(SummaryEntry {
+                        instance: instance_display.into_owned(),
+                        valid,
+                        errors: error_messages,
+                    });
+                }
+            }
+            if summary || matches!(output, Output::Summary) {
+                print_summary(schema_path, &summary_entries)?;
+            }
+        }
+        Err(error) => {
+            let message = describe_schema_build_error(&schema_json, &error);
+            print_schema_build_error(&schema_display, output, &message)?;
+            success = false;
+        }
+    }
+    Ok(success)
+}
 
-                    let instance_display = instance.to_string_lossy();
-                    let record = json!({
-                        "output": output_format,
-                        "schema": &schema_display,
-                        "instance": instance_display,
-                        "payload": payload,
+/// Validate newline-delimited JSON instances read from stdin, one per line.
+///
+/// Structured output records identify each instance by its 1-based line
+/// number and, if present, the document's top-level `id` field, since there's
+/// no filename to report.
+#[allow(clippy::too_many_arguments)]
+fn validate_stdin_instances(
+    schema_path: &Path,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    output: Output,
+    errors_only: bool,
+    summary: bool,
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+    map_uris: Vec<UriMapping>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    use std::io::BufRead;
+
+    let mut success = true;
+    let mut summary_entries = Vec::new();
+
+    let prepared = prepare_schema(schema_path, draft, assert_format, cache_dir, offline, http, map_uris)?;
+    let schema_json = prepared.schema_json;
+    let schema_display = schema_path.to_string_lossy().to_string();
+
+    match prepared.options.build(&schema_json) {
+        Ok(validator) => {
+            let output_format = output.as_str();
+            let mut rule_ids = std::collections::BTreeSet::new();
+            let mut failures = Vec::new();
+            let stdin = std::io::stdin();
+            for (index, line) in stdin.lock().lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let line_number = index + 1;
+                let instance_json: Value = match serde_json::from_str(&line) {
+                    Ok(instance_json) => instance_json,
+                    Err(parse_error) => {
+                        success = false;
+                        let label = format!("line {line_number}");
+                        match output {
+                            Output::Text => {
+                                if !summary {
+                                    println!("{label} - INVALID. Errors:");
+                                    println!("1. failed to parse line as JSON: {parse_error}");
+                                }
+                            }
+                            Output::Sarif => {
+                                let rule_id = "json-parse-error".to_string();
+                                rule_ids.insert(rule_id.clone());
+                                failures.push(SarifFailure {
+                                    rule_id,
+                                    message: format!("failed to parse line as JSON: {parse_error}"),
+                                    artifact_uri: format!("stdin:{line_number}"),
+                                    instance_pointer: String::new(),
+                                });
+                            }
+                            Output::Flag | Output::List | Output::Hierarchical => {
+                                let record = json!({
+                                    "output": output_format,
+                                    "schema": &schema_display,
+                                    "line": line_number,
+                                    "id": null,
+                                    "payload": {
+                                        "valid": false,
+                                        "error": format!("failed to parse line as JSON: {parse_error}"),
+                                    },
+                                });
+                                println!("{}", serde_json::to_string(&record)?);
+                            }
+                            Output::Summary => {}
+                        }
+                        summary_entries.push(SummaryEntry {
+                            instance: label,
+                            valid: false,
+                            errors: vec![format!("failed to parse line as JSON: {parse_error}")],
+                        });
+                        continue;
+                    }
+                };
+                let id = instance_json
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .map(str::to_owned);
+                let label = match &id {
+                    Some(id) => format!("line {line_number} (id: {id})"),
+                    None => format!("line {line_number}"),
+                };
+
+                if matches!(output, Output::Text) {
+                    let mut errors = validator.iter_errors(&instance_json);
+                    let mut error_messages = Vec::new();
+                    if let Some(first) = errors.next() {
+                        success = false;
+                        if !summary {
+                            println!("{label} - INVALID. Errors:");
+                            println!("1. {first}");
+                        }
+                        error_messages.push(first.to_string());
+                        for (i, error) in errors.enumerate() {
+                            if !summary {
+                                println!("{}. {error}", i + 2);
+                            }
+                            error_messages.push(error.to_string());
+                        }
+                    } else if !errors_only && !summary {
+                        println!("{label} - VALID");
+                    }
+                    summary_entries.push(SummaryEntry {
+                        instance: label,
+                        valid: error_messages.is_empty(),
+                        errors: error_messages,
                     });
-                    println!("{}", serde_json::to_string(&record)?);
+                } else if matches!(output, Output::Sarif) {
+                    let artifact_uri = format!("stdin:{line_number}");
+                    for error in validator.iter_errors(&instance_json) {
+                        success = false;
+                        let rule_id = error.kind().keyword().to_string();
+                        rule_ids.insert(rule_id.clone());
+                        failures.push(SarifFailure {
+                            rule_id,
+                            message: error.to_string(),
+                            artifact_uri: artifact_uri.clone(),
+                            instance_pointer: error.instance_path().as_str().to_string(),
+                        });
+                    }
+                } else {
+                    let evaluation = validator.evaluate(&instance_json);
+                    let flag_output = evaluation.flag();
+                    let list_value = serde_json::to_value(evaluation.list())?;
+                    let error_messages: Vec<String> = validator
+                        .iter_errors(&instance_json)
+                        .map(|error| error.to_string())
+                        .collect();
+
+                    if !matches!(output, Output::Summary) && !(errors_only && flag_output.valid) {
+                        let payload = match output {
+                            Output::Text | Output::Summary | Output::Sarif => {
+                                unreachable!("handled above")
+                            }
+                            Output::Flag => serde_json::to_value(flag_output)?,
+                            Output::List => list_value.clone(),
+                            Output::Hierarchical => serde_json::to_value(evaluation.hierarchical())?,
+                        };
+                        let record = json!({
+                            "output": output_format,
+                            "schema": &schema_display,
+                            "line": line_number,
+                            "id": id,
+                            "payload": payload,
+                        });
+                        println!("{}", serde_json::to_string(&record)?);
+                    }
 
                     if !flag_output.valid {
                         success = false;
                     }
+                    summary_entries.push(SummaryEntry {
+                        instance: label,
+                        valid: flag_output.valid,
+                        errors: error_messages,
+                    });
                 }
             }
+            if matches!(output, Output::Sarif) {
+                print_sarif(&rule_ids, &failures)?;
+            }
+            if summary || matches!(output, Output::Summary) {
+                print_summary(schema_path, &summary_entries)?;
+            }
         }
         Err(error) => {
-            println!("Schema is invalid. Error: {error}");
+            let message = describe_schema_build_error(&schema_json, &error);
+            print_schema_build_error(&schema_display, output, &message)?;
             success = false;
         }
     }
     Ok(success)
 }
 
+/// Validates one instance already routed to its schema by [`validate_routed_instances`],
+/// mirroring the per-output-mode branching `validate_instances`/`validate_stdin_instances`
+/// use, except the selected schema's path varies instance to instance instead of being fixed.
+#[allow(clippy::too_many_arguments)]
+fn validate_routed_instance(
+    router: &mut SchemaRouter,
+    label: &str,
+    instance_json: &Value,
+    output: Output,
+    errors_only: bool,
+    summary: bool,
+    success: &mut bool,
+    summary_entries: &mut Vec<SummaryEntry>,
+    rule_ids: &mut std::collections::BTreeSet<String>,
+    failures: &mut Vec<SarifFailure>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (schema_path, validator) = router
+        .route(instance_json)
+        .map_err(|message| -> Box<dyn std::error::Error> { message.into() })?;
+    let schema_display = schema_path.to_string_lossy().to_string();
+
+    if matches!(output, Output::Text) {
+        let mut errors = validator.iter_errors(instance_json);
+        let mut error_messages = Vec::new();
+        if let Some(first) = errors.next() {
+            *success = false;
+            if !summary {
+                println!("{label} - INVALID (schema: {schema_display}). Errors:");
+                println!("1. {first}");
+            }
+            error_messages.push(first.to_string());
+            for (i, error) in errors.enumerate() {
+                if !summary {
+                    println!("{}. {error}", i + 2);
+                }
+                error_messages.push(error.to_string());
+            }
+        } else if !errors_only && !summary {
+            println!("{label} - VALID (schema: {schema_display})");
+        }
+        summary_entries.push(SummaryEntry {
+            instance: label.to_string(),
+            valid: error_messages.is_empty(),
+            errors: error_messages,
+        });
+    } else if matches!(output, Output::Sarif) {
+        let mut error_messages = Vec::new();
+        for error in validator.iter_errors(instance_json) {
+            *success = false;
+            let rule_id = error.kind().keyword().to_string();
+            rule_ids.insert(rule_id.clone());
+            error_messages.push(error.to_string());
+            failures.push(SarifFailure {
+                rule_id,
+                message: error.to_string(),
+                artifact_uri: label.to_string(),
+                instance_pointer: error.instance_path().as_str().to_string(),
+            });
+        }
+        summary_entries.push(SummaryEntry {
+            instance: label.to_string(),
+            valid: error_messages.is_empty(),
+            errors: error_messages,
+        });
+    } else {
+        let evaluation = validator.evaluate(instance_json);
+        let flag_output = evaluation.flag();
+        let error_messages: Vec<String> = validator
+            .iter_errors(instance_json)
+            .map(|error| error.to_string())
+            .collect();
+
+        if !matches!(output, Output::Summary) && !(errors_only && flag_output.valid) {
+            let payload = match output {
+                Output::Flag => serde_json::to_value(flag_output)?,
+                Output::List => serde_json::to_value(evaluation.list())?,
+                Output::Hierarchical => serde_json::to_value(evaluation.hierarchical())?,
+                Output::Text | Output::Sarif | Output::Summary => unreachable!("handled above"),
+            };
+            let record = json!({
+                "output": output.as_str(),
+                "schema": &schema_display,
+                "instance": label,
+                "payload": payload,
+            });
+            println!("{}", serde_json::to_string(&record)?);
+        }
+
+        if !flag_output.valid {
+            *success = false;
+        }
+        summary_entries.push(SummaryEntry {
+            instance: label.to_string(),
+            valid: flag_output.valid,
+            errors: error_messages,
+        });
+    }
+    Ok(())
+}
+
+/// Validates every instance against a schema selected from `--schema-dir` by
+/// `--route-field`, instead of one schema shared by every instance. Compiled
+/// schemas are memoized in the [`SchemaRouter`] so a long stream of instances
+/// sharing a handful of schemas only compiles each one once.
+#[allow(clippy::too_many_arguments)]
+fn validate_routed_instances(
+    schema_dir: &Path,
+    route_field: &str,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    instances: Option<&[PathBuf]>,
+    stdin: bool,
+    output: Output,
+    errors_only: bool,
+    summary: bool,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    if instances.is_none() && !stdin {
+        return Err("--schema-dir requires --instance or --stdin to supply instances to route".into());
+    }
+
+    let mut router = SchemaRouter::new(
+        schema_dir.to_path_buf(),
+        route_field.to_string(),
+        draft,
+        assert_format,
+    );
+    let mut success = true;
+    let mut summary_entries = Vec::new();
+    let mut rule_ids = std::collections::BTreeSet::new();
+    let mut failures = Vec::new();
+
+    if stdin {
+        use std::io::BufRead;
+        let stdin_handle = std::io::stdin();
+        for (index, line) in stdin_handle.lock().lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let label = format!("line {}", index + 1);
+            let instance_json: Value = serde_json::from_str(&line)?;
+            validate_routed_instance(
+                &mut router,
+                &label,
+                &instance_json,
+                output,
+                errors_only,
+                summary,
+                &mut success,
+                &mut summary_entries,
+                &mut rule_ids,
+                &mut failures,
+            )?;
+        }
+    } else if let Some(instances) = instances {
+        for instance in discover_instances(instances)? {
+            let instance_json = read_json(&instance)??;
+            let label = instance.to_string_lossy().into_owned();
+            validate_routed_instance(
+                &mut router,
+                &label,
+                &instance_json,
+                output,
+                errors_only,
+                summary,
+                &mut success,
+                &mut summary_entries,
+                &mut rule_ids,
+                &mut failures,
+            )?;
+        }
+    }
+
+    if matches!(output, Output::Sarif) {
+        print_sarif(&rule_ids, &failures)?;
+    }
+    if summary || matches!(output, Output::Summary) {
+        print_summary(schema_dir, &summary_entries)?;
+    }
+    Ok(success)
+}
+
 fn main() -> ExitCode {
     let config = Cli::parse();
 
+    match config.command {
+        Some(Command::Completions { shell }) => {
+            clap_complete::generate(
+                shell,
+                &mut Cli::command(),
+                "jsonschema",
+                &mut std::io::stdout(),
+            );
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::Version { json }) => {
+            print_version_report(json);
+            return ExitCode::SUCCESS;
+        }
+        None => {}
+    }
+
     if config.version {
-        println!(concat!("Version: ", env!("CARGO_PKG_VERSION")));
+        print_version_report(false);
         return ExitCode::SUCCESS;
     }
 
     if let Some(schema) = config.schema {
-        if let Some(instances) = config.instances {
-            // - Some(true)  if --assert-format
-            // - Some(false) if --no-assert-format
-            // - None        if neither (use builderâ€™s default)
-            let assert_format = config.assert_format.or(config.no_assert_format);
-            return match validate_instances(
-                &instances,
+        // - Some(true)  if --assert-format
+        // - Some(false) if --no-assert-format
+        // - None        if neither (use builderâ€™s default)
+        let assert_format = config.assert_format.or(config.no_assert_format);
+
+        if config.apply_defaults {
+            let cache_dir = config.cache_dir.clone().unwrap_or_else(default_cache_dir);
+            let http = HttpConfig {
+                timeout: config.timeout.map(Duration::from_secs_f64),
+                connect_timeout: config.connect_timeout.map(Duration::from_secs_f64),
+                tls_verify: !config.insecure,
+                ca_cert: config.cacert.clone(),
+            };
+            if let Err(error) = run_apply_defaults(
                 &schema,
                 config.draft,
                 assert_format,
-                config.output,
-                config.errors_only,
+                config.instances.as_deref(),
+                config.stdin,
+                cache_dir,
+                config.offline,
+                http,
+                config.map_uri.clone().unwrap_or_default(),
             ) {
-                Ok(true) => ExitCode::SUCCESS,
-                Ok(false) => ExitCode::FAILURE,
-                Err(error) => {
-                    println!("Error: {error}");
-                    ExitCode::FAILURE
-                }
-            };
+                println!("Error: {error}");
+                return ExitCode::FAILURE;
+            }
         }
+
+        let cache_dir = config.cache_dir.unwrap_or_else(default_cache_dir);
+        let http = HttpConfig {
+            timeout: config.timeout.map(Duration::from_secs_f64),
+            connect_timeout: config.connect_timeout.map(Duration::from_secs_f64),
+            tls_verify: !config.insecure,
+            ca_cert: config.cacert,
+        };
+
+        let result = if config.stdin {
+            validate_stdin_instances(
+                &schema,
+                config.draft,
+                assert_format,
+                config.output,
+                config.errors_only,
+                config.summary,
+                cache_dir,
+                config.offline,
+                http,
+                config.map_uri.unwrap_or_default(),
+            )
+        } else if let Some(instances) = config.instances {
+            match discover_instances(&instances)
+                .map_err(|error| Box::new(error) as Box<dyn std::error::Error>)
+                .and_then(|discovered| {
+                    CustomFormats::load(&config.format_def, config.format_config.as_deref())
+                        .map(|custom_formats| (discovered, custom_formats))
+                }) {
+                Ok((discovered, custom_formats)) => validate_instances(
+                    &discovered,
+                    &schema,
+                    config.draft,
+                    assert_format,
+                    config.output,
+                    config.errors_only,
+                    config.summary,
+                    cache_dir,
+                    config.offline,
+                    http,
+                    config.map_uri.unwrap_or_default(),
+                    &custom_formats,
+                ),
+                Err(error) => Err(error),
+            }
+        } else {
+            validate_schema_only(
+                &schema,
+                config.draft,
+                assert_format,
+                config.output,
+                cache_dir,
+                config.offline,
+                http,
+                config.map_uri.unwrap_or_default(),
+            )
+        };
+
+        return match result {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(error) => {
+                println!("Error: {error}");
+                ExitCode::FAILURE
+            }
+        };
+    } else if let Some(schema_dir) = config.schema_dir {
+        let assert_format = config.assert_format.or(config.no_assert_format);
+        let result = validate_routed_instances(
+            &schema_dir,
+            &config.route_field,
+            config.draft,
+            assert_format,
+            config.instances.as_deref(),
+            config.stdin,
+            config.output,
+            config.errors_only,
+            config.summary,
+        );
+
+        return match result {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(error) => {
+                println!("Error: {error}");
+                ExitCode::FAILURE
+            }
+        };
     }
     ExitCode::SUCCESS
 }
@@ -0,0 +1,273 @@
+//! HTTP(S) schema and `$ref` retrieval with on-disk caching.
+//!
+//! Follows taplo's schema subsystem: fetched documents are cached on disk
+//! keyed by URL so repeat runs (and `--offline` runs) avoid the network.
+use std::{fs, path::PathBuf, time::Duration};
+
+use jsonschema::{Retrieve, Uri};
+use serde_json::Value;
+
+#[derive(Debug)]
+pub enum RetrieverError {
+    UnsupportedScheme(String),
+    Offline(String),
+    ReadFile { path: String, source: std::io::Error },
+    Parse { uri: String, source: serde_json::Error },
+    Fetch { uri: String, message: String },
+    CaCert { path: String, source: std::io::Error },
+}
+
+impl std::fmt::Display for RetrieverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnsupportedScheme(scheme) => write!(f, "unsupported URI scheme: {scheme}"),
+            Self::Offline(uri) => {
+                write!(f, "refusing to fetch {uri} over the network in --offline mode (not found in cache)")
+            }
+            Self::ReadFile { path, source } => write!(f, "failed to read {path}: {source}"),
+            Self::Parse { uri, source } => write!(f, "failed to parse {uri} as JSON: {source}"),
+            Self::Fetch { uri, message } => write!(f, "failed to fetch {uri}: {message}"),
+            Self::CaCert { path, source } => {
+                write!(f, "failed to read CA certificate {path}: {source}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RetrieverError {}
+
+/// A `PREFIX=LOCALDIR` rule for `--map-uri`: a `$ref` URI starting with
+/// `prefix` has that prefix replaced with `local_dir` and is read from disk
+/// instead of being resolved over HTTP(S).
+#[derive(Clone)]
+pub struct UriMapping {
+    pub prefix: String,
+    pub local_dir: PathBuf,
+}
+
+impl UriMapping {
+    /// Parses a single `--map-uri` argument in `PREFIX=LOCALDIR` form.
+    pub fn parse(raw: &str) -> Result<Self, String> {
+        let (prefix, local_dir) = raw
+            .split_once('=')
+            .ok_or_else(|| format!("'{raw}' is not in PREFIX=LOCALDIR form"))?;
+        if prefix.is_empty() {
+            return Err("PREFIX must not be empty".to_string());
+        }
+        Ok(Self {
+            prefix: prefix.to_string(),
+            local_dir: PathBuf::from(local_dir),
+        })
+    }
+}
+
+/// Intercepts `$ref` resolution for any URI matching one of its `--map-uri`
+/// rules, reading the mapped file from disk; anything unmapped falls through
+/// to `inner` (the network-capable [`CachingRetriever`]) unchanged, so
+/// `--timeout`/`--cacert`/`--insecure`/`--offline` only apply to URIs that
+/// aren't covered by a mapping.
+pub struct MappedRetriever {
+    mappings: Vec<UriMapping>,
+    inner: CachingRetriever,
+}
+
+impl MappedRetriever {
+    pub fn new(mappings: Vec<UriMapping>, inner: CachingRetriever) -> Self {
+        Self { mappings, inner }
+    }
+
+    fn fetch_mapped(&self, uri_str: &str, mapping: &UriMapping) -> Result<Value, RetrieverError> {
+        let rest = uri_str.strip_prefix(&mapping.prefix).unwrap_or(uri_str);
+        let path = mapping.local_dir.join(rest.trim_start_matches('/'));
+        let contents = fs::read(&path).map_err(|source| RetrieverError::ReadFile {
+            path: path.to_string_lossy().into_owned(),
+            source,
+        })?;
+        serde_json::from_slice(&contents).map_err(|source| RetrieverError::Parse {
+            uri: uri_str.to_string(),
+            source,
+        })
+    }
+}
+
+impl Retrieve for MappedRetriever {
+    fn retrieve(
+        &self,
+        uri: &Uri<String>,
+    ) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+        let uri_str = uri.as_str();
+        if let Some(mapping) = self.mappings.iter().find(|m| uri_str.starts_with(&m.prefix)) {
+            return self.fetch_mapped(uri_str, mapping).map_err(|e| Box::new(e) as _);
+        }
+        self.inner.retrieve(uri)
+    }
+}
+
+/// Configuration for HTTP(S) requests issued while resolving remote schemas.
+pub struct HttpConfig {
+    pub timeout: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    pub tls_verify: bool,
+    pub ca_cert: Option<PathBuf>,
+}
+
+/// Resolves `file://`, `http://` and `https://` references, caching fetched
+/// documents under `cache_dir` keyed by their URL.
+pub struct CachingRetriever {
+    cache_dir: PathBuf,
+    offline: bool,
+    http: HttpConfig,
+}
+
+impl CachingRetriever {
+    pub fn new(cache_dir: PathBuf, offline: bool, http: HttpConfig) -> Self {
+        Self {
+            cache_dir,
+            offline,
+            http,
+        }
+    }
+
+    fn cache_path(&self, uri: &str) -> PathBuf {
+        self.cache_dir.join(format!("{:016x}.json", fnv1a(uri)))
+    }
+
+    fn read_cached(&self, uri: &str) -> Option<Value> {
+        let contents = fs::read(self.cache_path(uri)).ok()?;
+        serde_json::from_slice(&contents).ok()
+    }
+
+    fn write_cache(&self, uri: &str, contents: &[u8]) {
+        if fs::create_dir_all(&self.cache_dir).is_ok() {
+            let _ = fs::write(self.cache_path(uri), contents);
+        }
+    }
+
+    fn fetch_file(&self, uri: &Uri<String>) -> Result<Value, RetrieverError> {
+        let path = uri.path().as_str();
+        #[cfg(not(target_os = "windows"))]
+        let path = percent_encoding::percent_decode_str(path)
+            .decode_utf8_lossy()
+            .into_owned();
+        #[cfg(target_os = "windows")]
+        let path = percent_encoding::percent_decode_str(path.trim_start_matches('/'))
+            .decode_utf8_lossy()
+            .into_owned();
+
+        let contents = fs::read(&path).map_err(|source| RetrieverError::ReadFile {
+            path: path.clone(),
+            source,
+        })?;
+        serde_json::from_slice(&contents).map_err(|source| RetrieverError::Parse {
+            uri: uri.as_str().to_string(),
+            source,
+        })
+    }
+
+    fn fetch_http(&self, uri: &Uri<String>) -> Result<Value, RetrieverError> {
+        let uri_str = uri.as_str();
+
+        if let Some(cached) = self.read_cached(uri_str) {
+            return Ok(cached);
+        }
+
+        if self.offline {
+            return Err(RetrieverError::Offline(uri_str.to_string()));
+        }
+
+        let mut builder = reqwest::blocking::Client::builder()
+            .danger_accept_invalid_certs(!self.http.tls_verify);
+        if let Some(timeout) = self.http.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(connect_timeout) = self.http.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(ca_cert) = &self.http.ca_cert {
+            let pem = fs::read(ca_cert).map_err(|source| RetrieverError::CaCert {
+                path: ca_cert.to_string_lossy().into_owned(),
+                source,
+            })?;
+            let cert =
+                reqwest::Certificate::from_pem(&pem).map_err(|error| RetrieverError::Fetch {
+                    uri: uri_str.to_string(),
+                    message: error.to_string(),
+                })?;
+            builder = builder.add_root_certificate(cert);
+        }
+        let client = builder.build().map_err(|error| RetrieverError::Fetch {
+            uri: uri_str.to_string(),
+            message: error.to_string(),
+        })?;
+
+        let response = client
+            .get(uri_str)
+            .send()
+            .map_err(|error| RetrieverError::Fetch {
+                uri: uri_str.to_string(),
+                message: error.to_string(),
+            })?;
+        let bytes = response
+            .error_for_status()
+            .map_err(|error| RetrieverError::Fetch {
+                uri: uri_str.to_string(),
+                message: error.to_string(),
+            })?
+            .bytes()
+            .map_err(|error| RetrieverError::Fetch {
+                uri: uri_str.to_string(),
+                message: error.to_string(),
+            })?;
+
+        self.write_cache(uri_str, &bytes);
+
+        serde_json::from_slice(&bytes).map_err(|source| RetrieverError::Parse {
+            uri: uri_str.to_string(),
+            source,
+        })
+    }
+}
+
+impl Retrieve for CachingRetriever {
+    fn retrieve(
+        &self,
+        uri: &Uri<String>,
+    ) -> Result<Value, Box<dyn std::error::Error + Send + Sync>> {
+        match uri.scheme().as_str() {
+            "file" => self.fetch_file(uri).map_err(|e| Box::new(e) as _),
+            "http" | "https" => self.fetch_http(uri).map_err(|e| Box::new(e) as _),
+            other => Err(Box::new(RetrieverError::UnsupportedScheme(other.to_string()))),
+        }
+    }
+}
+
+/// Fetch a standalone document (used for `http(s)://` schema arguments, which
+/// aren't resolved through `Retrieve` since they're the compilation root).
+pub fn fetch_standalone(retriever: &CachingRetriever, url: &str) -> Result<Value, RetrieverError> {
+    let uri: Uri<String> = url
+        .parse()
+        .map_err(|_| RetrieverError::Fetch {
+            uri: url.to_string(),
+            message: "invalid URL".to_string(),
+        })?;
+    retriever.fetch_http(&uri)
+}
+
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("jsonschema")
+}
+
+pub fn is_http_url(value: &str) -> bool {
+    value.starts_with("http://") || value.starts_with("https://")
+}
+
+/// Tiny FNV-1a hash, used only to derive stable, filesystem-safe cache keys.
+fn fnv1a(data: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    data.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
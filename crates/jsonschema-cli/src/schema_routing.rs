@@ -0,0 +1,97 @@
+//! Routes each instance to its own schema, named by a field on the instance
+//! itself, for `--schema-dir` batch validation.
+//!
+//! TODO: routed schemas are compiled with the CLI's `--draft`/`--assert-format`
+//! options but no retriever, so a routed schema with a remote `$ref` would fail
+//! to resolve; only `prepare_schema`'s main-schema path wires up
+//! `CachingRetriever`, and threading that through here too is a bigger change
+//! than this request's routing behavior needs.
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+use crate::Draft;
+
+/// Resolves `route` (the value of an instance's route field) against
+/// `schema_dir`, rejecting anything that doesn't stay inside it.
+pub(crate) fn resolve_route(schema_dir: &Path, route: &str) -> Result<PathBuf, String> {
+    let canonical_dir = schema_dir
+        .canonicalize()
+        .map_err(|error| format!("cannot read schema directory {}: {error}", schema_dir.display()))?;
+    let candidate = schema_dir.join(route);
+    let canonical = candidate.canonicalize().map_err(|error| {
+        format!("routed schema \"{route}\" does not exist under --schema-dir: {error}")
+    })?;
+    if !canonical.starts_with(&canonical_dir) {
+        return Err(format!("routed schema \"{route}\" escapes --schema-dir"));
+    }
+    Ok(canonical)
+}
+
+/// Compiles (and memoizes) one validator per distinct routed schema path, so
+/// a long stream of instances sharing a handful of schemas only pays the
+/// compile cost once per schema.
+pub(crate) struct SchemaRouter {
+    schema_dir: PathBuf,
+    route_field: String,
+    draft: Option<Draft>,
+    assert_format: Option<bool>,
+    compiled: HashMap<PathBuf, jsonschema::Validator>,
+}
+
+impl SchemaRouter {
+    pub(crate) fn new(
+        schema_dir: PathBuf,
+        route_field: String,
+        draft: Option<Draft>,
+        assert_format: Option<bool>,
+    ) -> Self {
+        Self {
+            schema_dir,
+            route_field,
+            draft,
+            assert_format,
+            compiled: HashMap::new(),
+        }
+    }
+
+    /// Routes `instance` to its schema, compiling (and caching) it on first
+    /// use, and returns the resolved schema path alongside its validator.
+    pub(crate) fn route(
+        &mut self,
+        instance: &Value,
+    ) -> Result<(PathBuf, &jsonschema::Validator), String> {
+        let route = instance
+            .get(&self.route_field)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("instance has no string \"{}\" field", self.route_field))?;
+        let path = resolve_route(&self.schema_dir, route)?;
+
+        if !self.compiled.contains_key(&path) {
+            let file = File::open(&path)
+                .map_err(|error| format!("cannot read {}: {error}", path.display()))?;
+            let schema_json: Value = serde_json::from_reader(BufReader::new(file))
+                .map_err(|error| format!("{} is not valid JSON: {error}", path.display()))?;
+            let mut options = jsonschema::options();
+            if let Some(draft) = self.draft {
+                options = options.with_draft(draft.into());
+            }
+            if let Some(assert_format) = self.assert_format {
+                options = options.should_validate_formats(assert_format);
+            }
+            let validator = options
+                .build(&schema_json)
+                .map_err(|error| format!("{} failed to compile: {error}", path.display()))?;
+            self.compiled.insert(path.clone(), validator);
+        }
+
+        let validator = self.compiled.get(&path).expect("just inserted above");
+        Ok((path, validator))
+    }
+}
@@ -36,10 +36,30 @@ fn test_version() {
     cmd.arg("--version");
     let output = cmd.output().unwrap();
     assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with(concat!("Version: ", env!("CARGO_PKG_VERSION"), "\n")));
+    assert!(stdout.contains("Supported drafts: 4, 6, 7, 2019-09, 2020-12"));
+    assert!(stdout.contains("Default draft: 2020-12"));
+    assert!(stdout.contains("Features: format-assertions"));
+}
+
+#[test]
+fn test_version_json_subcommand() {
+    let mut cmd = cli();
+    cmd.arg("version").arg("--json");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(report["version"], env!("CARGO_PKG_VERSION"));
+    assert_eq!(report["default_draft"], "2020-12");
     assert_eq!(
-        String::from_utf8_lossy(&output.stdout),
-        concat!("Version: ", env!("CARGO_PKG_VERSION"), "\n")
+        report["drafts"],
+        serde_json::json!(["4", "6", "7", "2019-09", "2020-12"])
     );
+    assert_eq!(report["features"], serde_json::json!(["format-assertions"]));
 }
 
 #[test]
@@ -127,6 +147,84 @@ fn test_multiple_instances() {
     assert_snapshot!(sanitized);
 }
 
+// Every generated completion script is expected to mention these flags,
+// since they're all defined directly on the top-level `Cli` command that
+// `completions` generates against.
+const KNOWN_FLAGS: &[&str] = &["--instance", "-d", "--assert-format", "--output", "--errors-only"];
+
+#[test]
+fn test_completions_bash() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("bash");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("_jsonschema()"));
+    for flag in KNOWN_FLAGS {
+        assert!(stdout.contains(flag), "bash completions missing {flag}");
+    }
+}
+
+#[test]
+fn test_completions_zsh() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("zsh");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.is_empty());
+    for flag in KNOWN_FLAGS {
+        assert!(stdout.contains(flag), "zsh completions missing {flag}");
+    }
+}
+
+#[test]
+fn test_completions_fish() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("fish");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.is_empty());
+    for flag in KNOWN_FLAGS {
+        assert!(stdout.contains(flag), "fish completions missing {flag}");
+    }
+}
+
+#[test]
+fn test_completions_elvish() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("elvish");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.is_empty());
+    for flag in KNOWN_FLAGS {
+        assert!(stdout.contains(flag), "elvish completions missing {flag}");
+    }
+}
+
+#[test]
+fn test_completions_powershell() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("powershell");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.is_empty());
+    for flag in KNOWN_FLAGS {
+        assert!(stdout.contains(flag), "powershell completions missing {flag}");
+    }
+}
+
+#[test]
+fn test_completions_invalid_shell() {
+    let mut cmd = cli();
+    cmd.arg("completions").arg("not-a-shell");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+}
+
 #[test]
 fn test_no_instances() {
     let dir = tempdir().unwrap();
@@ -714,6 +812,75 @@ fn test_errors_only_structured_output() {
     assert_eq!(records[0]["payload"]["valid"], false);
 }
 
+#[test]
+fn test_summary_flag_aggregates_counts() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let valid = create_temp_file(&dir, "valid.json", "42");
+    let invalid = create_temp_file(&dir, "invalid.json", r#""not an integer""#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&valid)
+        .arg("--instance")
+        .arg(&invalid)
+        .arg("--summary");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 1);
+    let report = &records[0];
+    assert_eq!(report["schema"], schema);
+    assert_eq!(report["total"], 2);
+    assert_eq!(report["valid"], 1);
+    assert_eq!(report["invalid"], 1);
+    assert_eq!(report["instances"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_summary_suppresses_per_instance_output() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let valid = create_temp_file(&dir, "valid.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&valid)
+        .arg("--summary");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains(" - VALID"));
+    assert_eq!(parse_ndjson(&stdout).len(), 1);
+}
+
+#[test]
+fn test_output_summary_with_structured_format() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let invalid = create_temp_file(&dir, "invalid.json", r#""nope""#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&invalid)
+        .arg("--output")
+        .arg("summary");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0]["invalid"], 1);
+    let errors = records[0]["instances"][0]["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].as_str().unwrap().contains("is not of type"));
+}
+
 #[test]
 fn test_validate_valid_schema() {
     let dir = tempdir().unwrap();
@@ -1169,3 +1336,475 @@ fn test_http_options_ndjson_output() {
     let output = cmd.output().unwrap();
     assert!(output.status.success());
 }
+
+#[test]
+fn test_offline_mode_rejects_uncached_remote_ref() {
+    let dir = tempdir().unwrap();
+    let cache_dir = dir.path().join("cache");
+    let schema = create_temp_file(
+        &dir,
+        "schema.json",
+        r#"{"$ref": "https://example.invalid/does-not-exist.json"}"#,
+    );
+    let instance = create_temp_file(&dir, "instance.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instance)
+        .arg("--offline")
+        .arg("--cache-dir")
+        .arg(&cache_dir);
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Error:"));
+}
+
+#[test]
+fn test_cache_dir_defaults_when_not_provided() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let instance = create_temp_file(&dir, "instance.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema).arg("--instance").arg(&instance);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_stdin_text_output() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--stdin")
+        .write_stdin("42\n\"not an integer\"\n");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("line 1 - VALID"));
+    assert!(stdout.contains("line 2 - INVALID"));
+}
+
+#[test]
+fn test_stdin_structured_output_carries_line_index_and_id() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "object"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--output")
+        .arg("flag")
+        .arg("--stdin")
+        .write_stdin("{\"id\": \"a\"}\n{}\n");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["line"], 1);
+    assert_eq!(records[0]["id"], "a");
+    assert_eq!(records[1]["line"], 2);
+    assert!(records[1]["id"].is_null());
+}
+
+#[test]
+fn test_stdin_malformed_line_reports_error_without_aborting_stream() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--output")
+        .arg("flag")
+        .arg("--stdin")
+        .write_stdin("1\nnot-json\n3\n");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0]["line"], 1);
+    assert_eq!(records[0]["payload"]["valid"], true);
+    assert_eq!(records[1]["line"], 2);
+    assert_eq!(records[1]["payload"]["valid"], false);
+    assert!(records[1]["payload"]["error"]
+        .as_str()
+        .unwrap()
+        .contains("failed to parse line as JSON"));
+    assert_eq!(records[2]["line"], 3);
+    assert_eq!(records[2]["payload"]["valid"], true);
+}
+
+#[test]
+fn test_stdin_conflicts_with_instance_flag() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let instance = create_temp_file(&dir, "instance.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instance)
+        .arg("--stdin");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_output_sarif_reports_failures_as_rules() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer", "minimum": 5}"#);
+    let valid = create_temp_file(&dir, "valid.json", "42");
+    let invalid = create_temp_file(&dir, "invalid.json", "1");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&valid)
+        .arg("--instance")
+        .arg(&invalid)
+        .arg("--output")
+        .arg("sarif");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let log: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(log["version"], "2.1.0");
+    assert!(log["$schema"].as_str().unwrap().contains("sarif-schema"));
+
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "jsonschema-cli");
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert!(rules.iter().any(|rule| rule["id"] == "minimum"));
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["ruleId"], "minimum");
+    let location = &results[0]["locations"][0]["physicalLocation"]["artifactLocation"];
+    assert!(location["uri"].as_str().unwrap().ends_with("invalid.json"));
+    assert_eq!(
+        results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+        ""
+    );
+}
+
+#[test]
+fn test_output_sarif_empty_results_when_all_valid() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let valid = create_temp_file(&dir, "valid.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&valid)
+        .arg("--output")
+        .arg("sarif");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let log: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(log["runs"][0]["results"].as_array().unwrap().len(), 0);
+}
+
+#[test]
+fn test_validate_invalid_schema_reports_meta_schema_violation() {
+    let dir = tempdir().unwrap();
+    // Fails to compile *and* fails meta-schema validation (bad `type` value).
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "invalid_type"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema);
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Schema is invalid"));
+    assert!(stdout.contains("does not conform to its meta-schema"));
+}
+
+#[test]
+fn test_validate_valid_schema_flag_output() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "string"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema).arg("--output").arg("flag");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("Should be valid JSON");
+    assert_eq!(json["output"], "flag");
+    assert_eq!(json["payload"]["valid"], true);
+}
+
+#[test]
+fn test_instance_directory_argument_discovers_nested_json_files() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+    let instances_dir = dir.path().join("instances");
+    let nested_dir = instances_dir.join("nested");
+    fs::create_dir_all(&nested_dir).unwrap();
+    fs::write(instances_dir.join("valid.json"), "42").unwrap();
+    fs::write(instances_dir.join("invalid.json"), r#""nope""#).unwrap();
+    fs::write(nested_dir.join("also_valid.json"), "7").unwrap();
+    fs::write(instances_dir.join("ignored.txt"), "not json").unwrap();
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instances_dir)
+        .arg("--output")
+        .arg("flag");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 3);
+    let mut by_instance = HashMap::new();
+    for record in records {
+        let instance = record["instance"].as_str().unwrap().to_string();
+        let valid = record["payload"]["valid"].as_bool().unwrap();
+        by_instance.insert(instance, valid);
+    }
+    assert_eq!(
+        by_instance
+            .iter()
+            .find(|(path, _)| std::path::Path::new(path).ends_with("valid.json"))
+            .map(|(_, valid)| *valid),
+        Some(true)
+    );
+    assert_eq!(
+        by_instance
+            .iter()
+            .find(|(path, _)| std::path::Path::new(path).ends_with("invalid.json"))
+            .map(|(_, valid)| *valid),
+        Some(false)
+    );
+    assert_eq!(
+        by_instance
+            .iter()
+            .find(|(path, _)| std::path::Path::new(path).ends_with("also_valid.json"))
+            .map(|(_, valid)| *valid),
+        Some(true)
+    );
+}
+
+#[test]
+fn test_apply_defaults_fills_in_missing_properties() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(
+        &dir,
+        "schema.json",
+        r#"{"properties": {"name": {"default": "anonymous"}, "age": {"type": "integer"}}}"#,
+    );
+    let instance = create_temp_file(&dir, "instance.json", r#"{"age": 30}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instance)
+        .arg("--apply-defaults");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let enriched: serde_json::Value = stdout
+        .lines()
+        .find_map(|line| serde_json::from_str(line).ok())
+        .expect("apply-defaults should emit a JSON document");
+    assert_eq!(enriched, serde_json::json!({"age": 30, "name": "anonymous"}));
+}
+
+#[test]
+fn test_apply_defaults_with_no_instance_uses_top_level_default() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"default": {"name": "anonymous"}}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema).arg("--apply-defaults");
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let emitted: serde_json::Value = stdout
+        .lines()
+        .find_map(|line| serde_json::from_str(line).ok())
+        .expect("apply-defaults should emit a JSON document");
+    assert_eq!(emitted, serde_json::json!({"name": "anonymous"}));
+}
+
+#[test]
+fn test_schema_dir_routes_each_instance_to_its_own_schema() {
+    let dir = tempdir().unwrap();
+    let schemas_dir = dir.path().join("schemas");
+    fs::create_dir_all(&schemas_dir).unwrap();
+    fs::write(
+        schemas_dir.join("int.json"),
+        r#"{"properties": {"value": {"type": "integer"}}}"#,
+    )
+    .unwrap();
+    fs::write(
+        schemas_dir.join("str.json"),
+        r#"{"properties": {"value": {"type": "string"}}}"#,
+    )
+    .unwrap();
+
+    let int_instance = create_temp_file(&dir, "int_instance.json", r#"{"$schema": "int.json", "value": 42}"#);
+    let str_instance = create_temp_file(&dir, "str_instance.json", r#"{"$schema": "str.json", "value": 42}"#);
+
+    let mut cmd = cli();
+    cmd.arg("--schema-dir")
+        .arg(&schemas_dir)
+        .arg("--instance")
+        .arg(&int_instance)
+        .arg("--instance")
+        .arg(&str_instance)
+        .arg("--output")
+        .arg("flag");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+
+    let records = parse_ndjson(&String::from_utf8_lossy(&output.stdout));
+    assert_eq!(records.len(), 2);
+    let mut by_instance = HashMap::new();
+    for record in &records {
+        let instance = record["instance"].as_str().unwrap().to_string();
+        by_instance.insert(instance, record.clone());
+    }
+    let int_record = by_instance
+        .iter()
+        .find(|(path, _)| path.ends_with("int_instance.json"))
+        .map(|(_, record)| record)
+        .unwrap();
+    assert!(int_record["schema"].as_str().unwrap().ends_with("int.json"));
+    assert_eq!(int_record["payload"]["valid"], true);
+
+    let str_record = by_instance
+        .iter()
+        .find(|(path, _)| path.ends_with("str_instance.json"))
+        .map(|(_, record)| record)
+        .unwrap();
+    assert!(str_record["schema"].as_str().unwrap().ends_with("str.json"));
+    assert_eq!(str_record["payload"]["valid"], false);
+}
+
+#[test]
+fn test_schema_dir_rejects_a_route_that_escapes_the_directory() {
+    let dir = tempdir().unwrap();
+    let schemas_dir = dir.path().join("schemas");
+    fs::create_dir_all(&schemas_dir).unwrap();
+    fs::write(schemas_dir.join("int.json"), r#"{"type": "integer"}"#).unwrap();
+    fs::write(dir.path().join("secret.json"), r#"{"type": "integer"}"#).unwrap();
+
+    let instance = create_temp_file(
+        &dir,
+        "instance.json",
+        r#"{"$schema": "../secret.json", "value": 1}"#,
+    );
+
+    let mut cmd = cli();
+    cmd.arg("--schema-dir")
+        .arg(&schemas_dir)
+        .arg("--instance")
+        .arg(&instance);
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+    let stderr_and_stdout = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stderr_and_stdout.contains("escapes --schema-dir"));
+}
+
+#[test]
+fn test_schema_dir_without_instance_or_stdin_fails() {
+    let dir = tempdir().unwrap();
+    let schemas_dir = dir.path().join("schemas");
+    fs::create_dir_all(&schemas_dir).unwrap();
+
+    let mut cmd = cli();
+    cmd.arg("--schema-dir").arg(&schemas_dir);
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("requires --instance or --stdin"));
+}
+
+#[test]
+fn test_map_uri_resolves_ref_from_local_dir_instead_of_network() {
+    let dir = tempdir().unwrap();
+    let vendor_dir = dir.path().join("vendor");
+    fs::create_dir_all(&vendor_dir).unwrap();
+    fs::write(vendor_dir.join("common.json"), r#"{"type": "integer"}"#).unwrap();
+
+    let schema = create_temp_file(
+        &dir,
+        "schema.json",
+        r#"{"$ref": "https://example.invalid/schemas/common.json"}"#,
+    );
+    let instance = create_temp_file(&dir, "instance.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instance)
+        .arg("--offline")
+        .arg("--map-uri")
+        .arg(format!(
+            "https://example.invalid/schemas/={}/",
+            vendor_dir.display()
+        ));
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+}
+
+#[test]
+fn test_map_uri_offline_still_rejects_unmapped_refs() {
+    let dir = tempdir().unwrap();
+    let vendor_dir = dir.path().join("vendor");
+    fs::create_dir_all(&vendor_dir).unwrap();
+    fs::write(vendor_dir.join("common.json"), r#"{"type": "integer"}"#).unwrap();
+
+    let schema = create_temp_file(
+        &dir,
+        "schema.json",
+        r#"{"$ref": "https://other.invalid/does-not-exist.json"}"#,
+    );
+    let instance = create_temp_file(&dir, "instance.json", "42");
+
+    let mut cmd = cli();
+    cmd.arg(&schema)
+        .arg("--instance")
+        .arg(&instance)
+        .arg("--offline")
+        .arg("--map-uri")
+        .arg(format!(
+            "https://example.invalid/schemas/={}/",
+            vendor_dir.display()
+        ));
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_map_uri_rejects_malformed_argument() {
+    let dir = tempdir().unwrap();
+    let schema = create_temp_file(&dir, "schema.json", r#"{"type": "integer"}"#);
+
+    let mut cmd = cli();
+    cmd.arg(&schema).arg("--map-uri").arg("no-equals-sign");
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("PREFIX=LOCALDIR"));
+}
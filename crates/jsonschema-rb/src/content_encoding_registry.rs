@@ -0,0 +1,78 @@
+//! Ruby-callable surface over the core crate's
+//! [`jsonschema::content_encoding::ContentEncodingRegistry`], exposed as
+//! `JSONSchema::ContentEncoding.decode`/`.register`.
+//!
+//! This is a deliberately partial delivery: the registry can decode
+//! `base16`, `base32`, `bech32`, and any decoder registered via `.register`,
+//! but nothing here hooks it into the `contentEncoding` keyword's own
+//! validator or into `jsonschema::options()` -- see the comment above
+//! `K::ContentEncoding` in `error_kind.rs` for why neither is reachable from
+//! this checkout. A caller that wants `contentEncoding` decoding today calls
+//! `JSONSchema::ContentEncoding.decode` explicitly after validation, rather
+//! than getting it for free as part of keyword enforcement.
+
+use std::sync::{Arc, OnceLock};
+
+use magnus::{block::Proc, gc::register_address, prelude::*, Error, RString, Ruby, Value};
+
+use jsonschema::content_encoding::ContentEncodingRegistry;
+
+fn registry() -> &'static ContentEncodingRegistry {
+    static REGISTRY: OnceLock<ContentEncodingRegistry> = OnceLock::new();
+    REGISTRY.get_or_init(ContentEncodingRegistry::with_builtins)
+}
+
+/// `JSONSchema::ContentEncoding.decode(name, value) -> String`: runs the
+/// decoder registered under `name` against `value`, returning the decoded
+/// bytes as a binary Ruby string. Raises `ArgumentError` if no decoder is
+/// registered under `name` or if decoding fails.
+pub(crate) fn decode(ruby: &Ruby, name: String, value: String) -> Result<Value, Error> {
+    let checker = registry().get(&name).ok_or_else(|| {
+        Error::new(
+            ruby.exception_arg_error(),
+            format!("No contentEncoding decoder registered for '{name}'"),
+        )
+    })?;
+    let decoded = checker(&value).map_err(|reason| {
+        Error::new(
+            ruby.exception_arg_error(),
+            format!("Failed to decode '{name}' content: {reason}"),
+        )
+    })?;
+    Ok(ruby.str_from_slice(&decoded).as_value())
+}
+
+/// `JSONSchema::ContentEncoding.register(name, &block)`: registers a decoder
+/// callable as `block.call(value) -> String`, whose return value is taken
+/// as the decoded bytes. Replaces any previous decoder registered under the
+/// same name, including the built-ins. Like `meta_registry`'s callbacks,
+/// there is no `unregister`, so the block lives for the process's remaining
+/// lifetime.
+pub(crate) fn register(name: String, callback: Value) -> Result<(), Error> {
+    let ruby = Ruby::get().expect("Ruby VM should be initialized");
+    let proc = Proc::from_value(callback).ok_or_else(|| {
+        Error::new(
+            ruby.exception_type_error(),
+            format!("contentEncoding decoder for '{name}' must be a callable (Proc or Lambda)"),
+        )
+    })?;
+
+    register_address(&callback);
+    let proc = magnus::value::Opaque::from(proc);
+
+    registry().register(
+        name,
+        Arc::new(move |value: &str| -> Result<Vec<u8>, String> {
+            let ruby = Ruby::get().expect("Ruby VM should be initialized");
+            let proc = ruby.get_inner(proc);
+            let result: Value = proc.call((value,)).map_err(|e| e.to_string())?;
+            let rstring = RString::from_value(result)
+                .ok_or_else(|| "decoder block must return a String".to_string())?;
+            // SAFETY: rstring is valid and we're in Ruby VM context (the
+            // block was just called on this same thread).
+            #[allow(unsafe_code)]
+            Ok(unsafe { rstring.as_slice() }.to_vec())
+        }),
+    );
+    Ok(())
+}
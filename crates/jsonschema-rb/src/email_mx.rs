@@ -0,0 +1,148 @@
+//! Optional DNS deliverability check for `format: "email"`, enabled via
+//! `EmailOptions#check_mx`.
+//!
+//! The external `jsonschema` crate's "email" format checker only validates
+//! syntax (RFC 5321/5322 local-part and domain shape), so there's no hook to
+//! layer an additional deliverability check onto it. When `check_mx` is
+//! enabled this binding instead registers its own `with_format("email", ...)`
+//! checker (see `options::make_options_from_kwargs`) that re-implements the
+//! same `require_tld`/`allow_domain_literal`/`allow_display_text`/
+//! `minimum_sub_domains` rules `EmailOptions` already exposes, then resolves
+//! the domain's MX records (falling back to A/AAAA per RFC 5321's implicit-MX
+//! rule) and fails the format if neither exists.
+//!
+//! A resolvable mail exchanger does not guarantee a mailbox actually exists
+//! at that address — this is a deliverability *hint*, not a proof the
+//! address can receive mail.
+//!
+//! The MX/A lookup in [`has_mail_exchanger`] is a synchronous, blocking DNS
+//! call with a 5s timeout, run once per validated email. `check` takes a
+//! `gvl_held` flag so it can release the GVL around that call with
+//! [`crate::without_gvl`] when it's invoked somewhere still holding it (see
+//! `options::make_options_from_kwargs`, which computes `gvl_held` from the
+//! same `has_ruby_callbacks` flag that decides whether the enclosing
+//! `validate`/`is_valid` call runs under `without_gvl` itself). When the
+//! enclosing call has already released the GVL, `gvl_held` is `false` and
+//! `check` calls the resolver directly instead of nesting another release.
+
+use std::time::Duration;
+
+use trust_dns_resolver::{
+    config::{ResolverConfig, ResolverOpts},
+    Resolver,
+};
+
+use crate::options::EmailOptions;
+
+/// Bounds how long a single MX/A/AAAA resolution may take, so a slow or
+/// unresponsive DNS server can't hang a validation call. Mirrors the role
+/// `options::timeout_duration` plays for HTTP fetches.
+const MX_RESOLUTION_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Splits `value` into `(local, domain)` honoring `allow_display_text`
+/// ("Display Name <addr>"), or `None` if the shape doesn't look like an
+/// email address at all.
+fn split_address(value: &str, eopts: &EmailOptions) -> Option<(String, String)> {
+    let addr = if eopts.allow_display_text {
+        match (value.find('<'), value.rfind('>')) {
+            (Some(start), Some(end)) if start < end => &value[start + 1..end],
+            _ => value,
+        }
+    } else if value.contains('<') || value.contains('>') {
+        return None;
+    } else {
+        value
+    };
+
+    let at = addr.rfind('@')?;
+    let (local, domain) = (&addr[..at], &addr[at + 1..]);
+    if local.is_empty() || domain.is_empty() {
+        return None;
+    }
+    Some((local.to_string(), domain.to_string()))
+}
+
+/// Whether `domain` satisfies `EmailOptions`'s syntax rules, ignoring
+/// deliverability. Intentionally a best-effort re-implementation, not a full
+/// RFC 5321/5322 parser.
+fn domain_syntax_ok(domain: &str, eopts: &EmailOptions) -> bool {
+    if let Some(literal) = domain.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        return eopts.allow_domain_literal && !literal.is_empty();
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.iter().any(|label| {
+        label.is_empty()
+            || label.starts_with('-')
+            || label.ends_with('-')
+            || !label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }) {
+        return false;
+    }
+
+    if eopts.require_tld && labels.len() < 2 {
+        return false;
+    }
+    if let Some(min) = eopts.minimum_sub_domains {
+        if labels.len() < min + 2 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Resolves `domain`'s MX records, falling back to A/AAAA per RFC 5321's
+/// implicit-MX rule when no MX record is published. Returns `false` (rather
+/// than erroring) on resolver failure or timeout, since that means no
+/// deliverable mail exchanger could be confirmed.
+fn has_mail_exchanger(domain: &str) -> bool {
+    let mut opts = ResolverOpts::default();
+    opts.timeout = MX_RESOLUTION_TIMEOUT;
+    let Ok(resolver) = Resolver::new(ResolverConfig::default(), opts) else {
+        return false;
+    };
+
+    let fqdn = if domain.ends_with('.') {
+        domain.to_string()
+    } else {
+        format!("{domain}.")
+    };
+
+    if let Ok(mx) = resolver.mx_lookup(&fqdn) {
+        if mx.iter().next().is_some() {
+            return true;
+        }
+    }
+
+    resolver
+        .lookup_ip(&fqdn)
+        .map(|records| records.iter().next().is_some())
+        .unwrap_or(false)
+}
+
+/// The `format: "email"` checker installed when `EmailOptions#check_mx` is
+/// true: validates syntax per `eopts`, then requires a resolvable mail
+/// exchanger for the domain.
+///
+/// `gvl_held` must be `true` when this is called from a context that is
+/// still holding the Ruby GVL (i.e. the enclosing `validate`/`is_valid` call
+/// took the `has_ruby_callbacks` branch rather than its own `without_gvl`
+/// branch — see `options::make_options_from_kwargs`), so the blocking DNS
+/// lookup below can release the GVL itself instead of stalling every other
+/// Ruby thread for up to 5s. When the caller already released the GVL,
+/// pass `false` so this doesn't attempt a second, nested release.
+pub(crate) fn check(value: &str, eopts: &EmailOptions, gvl_held: bool) -> bool {
+    let Some((_local, domain)) = split_address(value, eopts) else {
+        return false;
+    };
+    if !domain_syntax_ok(&domain, eopts) {
+        return false;
+    }
+    if gvl_held {
+        // SAFETY: `gvl_held` tells us this thread currently holds the GVL,
+        // and `has_mail_exchanger` makes no Ruby API calls.
+        unsafe { crate::without_gvl(|| has_mail_exchanger(&domain)) }.unwrap_or(false)
+    } else {
+        has_mail_exchanger(&domain)
+    }
+}
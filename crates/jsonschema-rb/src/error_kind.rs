@@ -6,6 +6,8 @@ use magnus::{
     Error, RModule, Ruby, TypedData, Value,
 };
 
+use serde_json::Value as JsonValue;
+
 use crate::{ser::value_to_ruby, static_id::define_rb_intern};
 
 define_rb_intern!(static ID_NAME: "name");
@@ -24,6 +26,7 @@ define_rb_intern!(static ID_MULTIPLE_OF: "multiple_of");
 define_rb_intern!(static ID_SCHEMA: "schema");
 define_rb_intern!(static ID_REASON: "reason");
 define_rb_intern!(static ID_PROPERTY: "property");
+define_rb_intern!(static ID_PROPERTIES: "properties");
 define_rb_intern!(static ID_TYPES: "types");
 define_rb_intern!(static ID_PATTERN: "pattern");
 define_rb_intern!(static ID_CTX_INSTANCE_PATH: "instance_path");
@@ -71,6 +74,12 @@ fn rb_hash2(
 }
 
 /// Convert anyOf/oneOf context into a Ruby array of error branch arrays.
+///
+/// Each branch entry's `:kind` is a full [`ValidationErrorKind`] (built via
+/// [`ValidationErrorKind::new`] with the same `mask`), not just the keyword
+/// name — since that constructor itself calls back into this function for
+/// `AnyOf`/`OneOf*` kinds, nested `oneOf`-in-`oneOf` trees stay fully
+/// introspectable from Ruby however deep they go.
 fn context_to_ruby(
     ruby: &Ruby,
     context: &[Vec<jsonschema::ValidationError<'static>>],
@@ -102,7 +111,8 @@ fn context_to_ruby(
                 sym_evaluation_path,
                 ruby.into_value(e.evaluation_path().as_str()),
             )?;
-            hash.aset(sym_kind, ruby.into_value(e.kind().keyword()))?;
+            let kind_obj = ValidationErrorKind::new(ruby, e.kind(), mask)?;
+            hash.aset(sym_kind, ruby.into_value(kind_obj))?;
             errors.push(hash)?;
         }
         branches.push(errors)?;
@@ -154,6 +164,27 @@ impl ValidationErrorKind {
                 value_to_ruby(ruby, expected_value)?,
             )?,
             K::Contains | K::FalseSchema | K::UniqueItems => ruby.hash_new().as_value(),
+            // STATUS: partially delivered, rest needs escalation to whoever owns
+            // this backlog. The decoder half (a registry plus base16/base32/
+            // bech32 built-ins, `jsonschema::content_encoding`) is now reachable
+            // from Ruby as `JSONSchema::ContentEncoding.decode`/`.register` (see
+            // `content_encoding_registry.rs`), so callers can decode a
+            // `contentEncoding`-tagged string themselves today. What's still
+            // missing is wiring that registry *into* validation itself: a
+            // `with_content_encoding`/`with_content_media_type` pair on the
+            // options builder (mirroring `with_format`) and a hook in the
+            // `contentEncoding` keyword's own `compile`/`validate` to consult it.
+            // Neither the options builder's internals nor that keyword's
+            // validator are part of this checkout, so that half can't be added
+            // from here without guessing at their shape. That gap is also why
+            // the original "pluggable contentEncoding/contentMediaType
+            // validation and decoding" request (check/convert split,
+            // contentMediaType support, built-in base64/application-json
+            // handlers, feeding a decoded payload into the media-type check)
+            // is still open: `JSONSchema::ContentEncoding` covers the decode
+            // step only, not a `contentMediaType` equivalent or the
+            // automatic validate/evaluate-time enforcement the request asked
+            // for.
             K::ContentEncoding { content_encoding } => rb_hash1(
                 ruby,
                 ID_CONTENT_ENCODING.to_symbol(),
@@ -242,11 +273,26 @@ impl ValidationErrorKind {
                 ID_ERROR.to_symbol(),
                 ruby.into_value(err.to_string().as_str()),
             )?,
-            K::Required { property } => rb_hash1(
-                ruby,
-                ID_PROPERTY.to_symbol(),
-                value_to_ruby(ruby, property)?,
-            )?,
+            // The core keyword now aggregates every missing name for an
+            // object into a single error, so `property` usually holds a
+            // JSON array rather than one string. Expose the full list as
+            // `:properties` and keep `:property` populated with the first
+            // entry so existing callers that only look at `:property`
+            // keep working unchanged.
+            K::Required { property } => {
+                let properties: Vec<JsonValue> = match property {
+                    JsonValue::Array(items) => items.clone(),
+                    other => vec![other.clone()],
+                };
+                let first = properties.first().cloned().unwrap_or(JsonValue::Null);
+                rb_hash2(
+                    ruby,
+                    ID_PROPERTY.to_symbol(),
+                    value_to_ruby(ruby, &first)?,
+                    ID_PROPERTIES.to_symbol(),
+                    value_to_ruby(ruby, &JsonValue::Array(properties))?,
+                )?
+            }
             K::Type { kind } => {
                 let types: Vec<Value> = match kind {
                     jsonschema::error::TypeKind::Single(ty) => vec![ruby.into_value(ty.as_str())],
@@ -1,7 +1,12 @@
 //! Evaluation output wrapper for Ruby
 //!
-//! Provides full JSON Schema output format support: flag, list, and hierarchical.
-use magnus::{method, prelude::*, Error, RModule, Ruby, Value};
+//! Provides full JSON Schema output format support: flag, list, basic, and hierarchical.
+use magnus::{
+    method,
+    prelude::*,
+    scan_args::{get_kwargs, scan_args, KwArgs},
+    Error, RModule, Ruby, Value,
+};
 
 use crate::{
     ser::{serialize_to_ruby, value_to_ruby},
@@ -13,16 +18,22 @@ define_rb_intern!(static ID_ABSOLUTE_KEYWORD_LOCATION: "absoluteKeywordLocation"
 define_rb_intern!(static ID_INSTANCE_LOCATION: "instanceLocation");
 define_rb_intern!(static ID_ANNOTATIONS: "annotations");
 define_rb_intern!(static ID_ERROR: "error");
+define_rb_intern!(static ID_SNIPPET: "snippet");
 define_rb_intern!(static ID_VALID: "valid");
+define_rb_intern!(static KW_SNIPPETS: "snippets");
 
 #[magnus::wrap(class = "JSONSchema::Evaluation", free_immediately, size)]
 pub struct Evaluation {
     inner: jsonschema::Evaluation,
+    instance: serde_json::Value,
 }
 
 impl Evaluation {
-    pub fn new(output: jsonschema::Evaluation) -> Self {
-        Evaluation { inner: output }
+    pub fn new(output: jsonschema::Evaluation, instance: serde_json::Value) -> Self {
+        Evaluation {
+            inner: output,
+            instance,
+        }
     }
 
     fn is_valid(&self) -> bool {
@@ -43,6 +54,18 @@ impl Evaluation {
         serialize_to_ruby(ruby, &list_output)
     }
 
+    /// Draft 2020-12 "basic" output format: a flattened, single-level array
+    /// of output units (`valid`/`evaluationPath`/`schemaLocation`/
+    /// `instanceLocation` plus `annotations`/`errors`), with nested details
+    /// collapsed rather than nested the way `hierarchical` nests them.
+    /// `list` already produces exactly this shape, so `basic` exposes it
+    /// under the name the spec's own output vocabulary uses, alongside
+    /// `flag` and `hierarchical`.
+    fn basic(ruby: &Ruby, rb_self: &Self) -> Result<Value, Error> {
+        let list_output = rb_self.inner.list();
+        serialize_to_ruby(ruby, &list_output)
+    }
+
     /// Nested tree structure following the schema structure.
     fn hierarchical(ruby: &Ruby, rb_self: &Self) -> Result<Value, Error> {
         let hierarchical_output = rb_self.inner.hierarchical();
@@ -73,14 +96,44 @@ impl Evaluation {
         Ok(arr.as_value())
     }
 
-    fn errors(ruby: &Ruby, rb_self: &Self) -> Result<Value, Error> {
+    /// Each error's hash gains a `:snippet` key — the instance line the error
+    /// points at, underlined at the exact span `instanceLocation` names, with
+    /// the error message on the line beneath — when called as
+    /// `errors(snippets: true)`. Building the snippet index pretty-prints the
+    /// whole instance and re-parses it for spans, so it's opt-in rather than
+    /// always paid for.
+    fn errors(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<Value, Error> {
+        let parsed_args = scan_args::<(), (), (), (), _, ()>(args)?;
+        let kw: KwArgs<(), (Option<bool>,), ()> =
+            get_kwargs(parsed_args.keywords, &[], &[*KW_SNIPPETS])?;
+        let snippets_requested = kw.optional.0.unwrap_or(false);
+
+        let snippet_source = if snippets_requested {
+            let text = serde_json::to_string_pretty(&rb_self.instance).map_err(|err| {
+                Error::new(
+                    ruby.exception_runtime_error(),
+                    format!("failed to render instance snippet: {err}"),
+                )
+            })?;
+            let (_, spans) = jsonschema::spans::parse_with_spans(&text).map_err(|err| {
+                Error::new(
+                    ruby.exception_runtime_error(),
+                    format!("failed to render instance snippet: {err}"),
+                )
+            })?;
+            Some((text, spans))
+        } else {
+            None
+        };
+
         let schema_loc = ID_SCHEMA_LOCATION.to_symbol();
         let abs_kw_loc = ID_ABSOLUTE_KEYWORD_LOCATION.to_symbol();
         let inst_loc = ID_INSTANCE_LOCATION.to_symbol();
         let error_key = ID_ERROR.to_symbol();
+        let snippet_key = ID_SNIPPET.to_symbol();
         let arr = ruby.ary_new();
         for entry in rb_self.inner.iter_errors() {
-            let hash = ruby.hash_new_capa(4);
+            let hash = ruby.hash_new_capa(if snippet_source.is_some() { 5 } else { 4 });
             hash.aset(schema_loc, entry.schema_location)?;
             if let Some(uri) = entry.absolute_keyword_location {
                 hash.aset(abs_kw_loc, uri.as_str())?;
@@ -88,7 +141,17 @@ impl Evaluation {
                 hash.aset(abs_kw_loc, ruby.qnil())?;
             }
             hash.aset(inst_loc, entry.instance_location.as_str())?;
-            hash.aset(error_key, entry.error.to_string())?;
+            let message = entry.error.to_string();
+            if let Some((text, spans)) = &snippet_source {
+                match spans.get(entry.instance_location.as_str()) {
+                    Some(span) => {
+                        let snippet = jsonschema::spans::render_snippet(text, span);
+                        hash.aset(snippet_key, format!("{snippet}\n{message}"))?;
+                    }
+                    None => hash.aset(snippet_key, ruby.qnil())?,
+                }
+            }
+            hash.aset(error_key, message)?;
             arr.push(hash)?;
         }
         Ok(arr.as_value())
@@ -107,9 +170,10 @@ pub fn define_class(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     class.define_method("valid?", method!(Evaluation::is_valid, 0))?;
     class.define_method("flag", method!(Evaluation::flag, 0))?;
     class.define_method("list", method!(Evaluation::list, 0))?;
+    class.define_method("basic", method!(Evaluation::basic, 0))?;
     class.define_method("hierarchical", method!(Evaluation::hierarchical, 0))?;
     class.define_method("annotations", method!(Evaluation::annotations, 0))?;
-    class.define_method("errors", method!(Evaluation::errors, 0))?;
+    class.define_method("errors", method!(Evaluation::errors, -1))?;
     class.define_method("inspect", method!(Evaluation::inspect, 0))?;
 
     Ok(())
@@ -5,8 +5,11 @@
 #![allow(clippy::unused_self)]
 #![allow(clippy::struct_field_names)]
 
+mod content_encoding_registry;
+mod email_mx;
 mod error_kind;
 mod evaluation;
+mod meta_registry;
 mod options;
 mod registry;
 mod retriever;
@@ -20,8 +23,9 @@ use magnus::{
     method,
     prelude::*,
     scan_args::scan_args,
-    value::{Lazy, ReprValue},
-    DataTypeFunctions, Error, Exception, ExceptionClass, RClass, RModule, RObject, Ruby, Value,
+    value::{Lazy, Opaque, ReprValue},
+    DataTypeFunctions, Error, Exception, ExceptionClass, RArray, RClass, RHash, RModule, RObject,
+    Ruby, Value,
 };
 use referencing::unescape_segment;
 use std::{
@@ -35,11 +39,11 @@ use crate::{
     evaluation::Evaluation,
     options::{
         extract_evaluate_kwargs, extract_kwargs, extract_kwargs_no_draft, make_options_from_kwargs,
-        parse_draft_symbol, CallbackRoots, CompilationRoots, CompilationRootsRef, ExtractedKwargs,
-        ParsedOptions,
+        parse_draft_symbol, take_format_failure_reason, CallbackRoots, CompilationRoots,
+        CompilationRootsRef, ExtractedKwargs, ParsedOptions,
     },
     registry::Registry,
-    retriever::{retriever_error_message, RubyRetriever},
+    retriever::{make_registry_retriever, retriever_error_message, AnyRetriever, NativeHttpConfig},
     ser::{to_schema_value, to_value},
     static_id::define_rb_intern,
 };
@@ -58,6 +62,8 @@ define_rb_intern!(static ID_AT_SCHEMA_PATH_POINTER: "@schema_path_pointer");
 define_rb_intern!(static ID_AT_EVALUATION_PATH_POINTER: "@evaluation_path_pointer");
 define_rb_intern!(static ID_AT_KIND: "@kind");
 define_rb_intern!(static ID_AT_INSTANCE: "@instance");
+define_rb_intern!(static ID_AT_DETAILED_MESSAGE: "@detailed_message");
+define_rb_intern!(static ID_AT_SCHEMA_FRAGMENT: "@schema_fragment");
 
 define_rb_intern!(static ID_SYM_MESSAGE: "message");
 define_rb_intern!(static ID_SYM_VERBOSE_MESSAGE: "verbose_message");
@@ -69,6 +75,10 @@ define_rb_intern!(static ID_SYM_INSTANCE: "instance");
 define_rb_intern!(static ID_SYM_INSTANCE_PATH_POINTER: "instance_path_pointer");
 define_rb_intern!(static ID_SYM_SCHEMA_PATH_POINTER: "schema_path_pointer");
 define_rb_intern!(static ID_SYM_EVALUATION_PATH_POINTER: "evaluation_path_pointer");
+define_rb_intern!(static ID_SYM_DETAILED_MESSAGE: "detailed_message");
+define_rb_intern!(static ID_SYM_SCHEMA_FRAGMENT: "schema_fragment");
+define_rb_intern!(static ID_SYM_POINTER: "pointer");
+define_rb_intern!(static ID_SYM_ERROR: "error");
 
 struct BuiltValidator {
     validator: jsonschema::Validator,
@@ -79,7 +89,7 @@ struct BuiltValidator {
 fn build_validator(
     ruby: &Ruby,
     options: ValidationOptions,
-    retriever: Option<RubyRetriever>,
+    retriever: Option<AnyRetriever>,
     callback_roots: CallbackRoots,
     compilation_roots: Arc<CompilationRoots>,
     schema: &serde_json::Value,
@@ -148,6 +158,74 @@ impl Drop for CallbackRootGuard {
     }
 }
 
+/// RAII guard that publishes `context` (the `context:` kwarg passed to a
+/// validation call) via [`CURRENT_CONTEXT`] for the duration of that call, so
+/// `current_context` can hand it to format/keyword callbacks invoked deep
+/// inside the compiled validator, which have no other way to reach it.
+///
+/// Restores the previous thread-local value on drop (rather than clearing it)
+/// so a callback that triggers nested validation doesn't clobber the outer
+/// call's context once it returns.
+struct ContextGuard {
+    previous: Option<Opaque<Value>>,
+    registered: Option<Value>,
+}
+
+impl ContextGuard {
+    fn new(context: Option<Value>) -> Self {
+        let context = context.filter(|value| !value.is_nil());
+        if let Some(value) = &context {
+            register_address(value);
+        }
+        let previous = CURRENT_CONTEXT.with(|cell| cell.replace(context.map(Opaque::from)));
+        Self {
+            previous,
+            registered: context,
+        }
+    }
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CURRENT_CONTEXT.with(|cell| *cell.borrow_mut() = self.previous);
+        if let Some(value) = &self.registered {
+            unregister_address(value);
+        }
+    }
+}
+
+/// Returns the `context:` value published by the innermost [`ContextGuard`]
+/// currently on the call stack, or `nil` if no validation call set one.
+pub(crate) fn current_context(ruby: &Ruby) -> Value {
+    CURRENT_CONTEXT
+        .with(|cell| *cell.borrow())
+        .map(|opaque| ruby.get_inner(opaque))
+        .unwrap_or_else(|| ruby.qnil().as_value())
+}
+
+/// Roots a Ruby retriever callback (if any) for the duration of meta-schema
+/// compilation — the only point a `$ref`/`$schema` resolver callback into
+/// Ruby runs. One-off like `CallbackRootGuard`, but for the single retriever
+/// value `Meta.*` accepts rather than a whole `CallbackRoots` collection.
+struct MetaRetrieverRootGuard(Option<Value>);
+
+impl MetaRetrieverRootGuard {
+    fn new(value: Option<Value>) -> Self {
+        if let Some(value) = &value {
+            register_address(value);
+        }
+        Self(value)
+    }
+}
+
+impl Drop for MetaRetrieverRootGuard {
+    fn drop(&mut self) {
+        if let Some(value) = &self.0 {
+            unregister_address(value);
+        }
+    }
+}
+
 fn build_parsed_options(
     ruby: &Ruby,
     kw: ExtractedKwargs,
@@ -158,6 +236,7 @@ fn build_parsed_options(
         validate_formats,
         ignore_unknown_formats,
         mask,
+        verbose,
         base_uri,
         retriever,
         formats,
@@ -168,12 +247,13 @@ fn build_parsed_options(
         Some(val) => Some(parse_draft_symbol(ruby, val)?),
         None => None,
     };
-    make_options_from_kwargs(
+    let mut parsed = make_options_from_kwargs(
         ruby,
         draft_override.or(parsed_draft),
         validate_formats,
         ignore_unknown_formats,
         mask,
+        verbose,
         base_uri,
         retriever,
         formats,
@@ -182,13 +262,18 @@ fn build_parsed_options(
         kw.pattern_options,
         kw.email_options,
         kw.http_options,
-    )
+    )?;
+    parsed.context = kw.context;
+    Ok(parsed)
 }
 
 thread_local! {
     static LAST_CALLBACK_ERROR: RefCell<Option<Error>> = const { RefCell::new(None) };
     /// When `true`, the custom panic hook suppresses output (inside `catch_unwind` blocks).
     static SUPPRESS_PANIC_OUTPUT: RefCell<bool> = const { RefCell::new(false) };
+    /// The `context:` value for the validation call currently in progress on this
+    /// thread, set by [`ContextGuard`] and read by `current_context`.
+    static CURRENT_CONTEXT: RefCell<Option<Opaque<Value>>> = const { RefCell::new(None) };
 }
 
 static VALIDATION_ERROR_CLASS: Lazy<ExceptionClass> = Lazy::new(|ruby| {
@@ -336,13 +421,107 @@ fn build_verbose_message(
     message
 }
 
+/// Greedily word-wrap `text` so no line exceeds `width` columns, preserving existing newlines.
+fn wrap_text(text: &str, width: usize) -> String {
+    let mut wrapped = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            wrapped.push('\n');
+        }
+        let mut column = 0;
+        for (j, word) in line.split(' ').enumerate() {
+            if j > 0 {
+                if column + 1 + word.len() > width && column > 0 {
+                    wrapped.push('\n');
+                    column = 0;
+                } else {
+                    wrapped.push(' ');
+                    column += 1;
+                }
+            }
+            wrapped.push_str(word);
+            column += word.len();
+        }
+    }
+    wrapped
+}
+
+/// Pretty-print `value` and append it to `out`, indented under a `At ... path X:` header.
+fn push_indented_pretty(out: &mut String, value: &serde_json::Value) {
+    let pretty = serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string());
+    for line in pretty.lines() {
+        out.push_str("    ");
+        out.push_str(line);
+        out.push('\n');
+    }
+    out.pop(); // drop the trailing newline from the last line
+}
+
+/// Build a "detailed" error message: the wrapped one-line message, followed by the
+/// offending instance fragment and (when resolvable) the relevant schema fragment,
+/// each resolved from `instance_path`/`schema_path` and pretty-printed. Mirrors the
+/// formatted `Display` used by jsonschema-valid, for more readable CLI/test output
+/// than the single-line `message`.
+fn build_detailed_message(
+    message: &str,
+    schema_path: &jsonschema::paths::Location,
+    instance_path: &jsonschema::paths::Location,
+    root_schema: Option<&serde_json::Value>,
+    root_instance: Option<&serde_json::Value>,
+    failing_instance: &serde_json::Value,
+) -> String {
+    let schema_path_str = schema_path.as_str();
+    let instance_path_str = instance_path.as_str();
+
+    let instance_fragment = root_instance
+        .and_then(|root| root.pointer(instance_path_str))
+        .unwrap_or(failing_instance);
+    let schema_fragment = root_schema.and_then(|root| root.pointer(schema_path_str));
+
+    let mut detailed = wrap_text(message, 100);
+
+    detailed.push_str("\n\nAt instance path ");
+    detailed.push_str(if instance_path_str.is_empty() {
+        "/"
+    } else {
+        instance_path_str
+    });
+    detailed.push_str(":\n");
+    push_indented_pretty(&mut detailed, instance_fragment);
+
+    if let Some(schema_fragment) = schema_fragment {
+        detailed.push_str("\n\nAt schema path ");
+        detailed.push_str(if schema_path_str.is_empty() {
+            "/"
+        } else {
+            schema_path_str
+        });
+        detailed.push_str(":\n");
+        push_indented_pretty(&mut detailed, schema_fragment);
+    }
+
+    detailed
+}
+
 /// Compute the display message for a validation error, respecting the mask option.
+///
+/// A `format` failure whose Ruby checker returned a custom reason string (see
+/// `RubyFormatChecker::check`) uses that reason verbatim instead of the
+/// generic "does not match format" message — unless `mask` is set, in which
+/// case the mask wins, since a custom reason could itself echo back the
+/// masked value.
 fn error_message(error: &jsonschema::ValidationError<'_>, mask: Option<&str>) -> String {
     if let Some(mask) = mask {
-        error.masked_with(mask).to_string()
-    } else {
-        error.to_string()
+        return error.masked_with(mask).to_string();
     }
+    if let jsonschema::error::ValidationErrorKind::Format { format } = error.kind() {
+        if let Some(value) = error.instance().as_str() {
+            if let Some(reason) = take_format_failure_reason(format, value) {
+                return reason;
+            }
+        }
+    }
+    error.to_string()
 }
 
 /// Convert a jsonschema `ValidationError` to a Ruby `ValidationError`.
@@ -350,8 +529,10 @@ fn into_ruby_error(
     ruby: &Ruby,
     error: jsonschema::ValidationError<'_>,
     root_instance: Option<&serde_json::Value>,
+    root_schema: Option<&serde_json::Value>,
     message: &str,
     mask: Option<&str>,
+    verbose: bool,
 ) -> Result<Value, Error> {
     let rb_message = ruby.into_value(message);
     let verbose_message = build_verbose_message(
@@ -362,6 +543,19 @@ fn into_ruby_error(
         error.instance(),
         mask,
     );
+    let detailed_message = verbose.then(|| {
+        build_detailed_message(
+            message,
+            error.schema_path(),
+            error.instance_path(),
+            root_schema,
+            root_instance,
+            error.instance(),
+        )
+    });
+    let schema_fragment = verbose
+        .then(|| root_schema.and_then(|root| root.pointer(error.schema_path().as_str())))
+        .flatten();
 
     let (instance, kind, instance_path, schema_path, evaluation_path) = error.into_parts();
 
@@ -405,6 +599,18 @@ fn into_ruby_error(
     exc.ivar_set(*ID_AT_EVALUATION_PATH_POINTER, evaluation_path_ptr)?;
     exc.ivar_set(*ID_AT_KIND, ruby.into_value(kind_obj))?;
     exc.ivar_set(*ID_AT_INSTANCE, rb_instance)?;
+    if let Some(detailed_message) = detailed_message {
+        exc.ivar_set(
+            *ID_AT_DETAILED_MESSAGE,
+            ruby.into_value(detailed_message.as_str()),
+        )?;
+    }
+    if let Some(schema_fragment) = schema_fragment {
+        exc.ivar_set(
+            *ID_AT_SCHEMA_FRAGMENT,
+            ser::value_to_ruby(ruby, schema_fragment)?,
+        )?;
+    }
 
     Ok(exc.as_value())
 }
@@ -414,10 +620,20 @@ fn to_ruby_error_value(
     ruby: &Ruby,
     error: jsonschema::ValidationError<'_>,
     root_instance: Option<&serde_json::Value>,
+    root_schema: Option<&serde_json::Value>,
     mask: Option<&str>,
+    verbose: bool,
 ) -> Result<Value, Error> {
     let message = error_message(&error, mask);
-    into_ruby_error(ruby, error, root_instance, &message, mask)
+    into_ruby_error(
+        ruby,
+        error,
+        root_instance,
+        root_schema,
+        &message,
+        mask,
+        verbose,
+    )
 }
 
 fn referencing_error(ruby: &Ruby, message: String) -> Error {
@@ -429,10 +645,20 @@ fn raise_validation_error(
     ruby: &Ruby,
     error: jsonschema::ValidationError<'_>,
     root_instance: Option<&serde_json::Value>,
+    root_schema: Option<&serde_json::Value>,
     mask: Option<&str>,
+    verbose: bool,
 ) -> Error {
     let message = error_message(&error, mask);
-    match into_ruby_error(ruby, error, root_instance, &message, mask) {
+    match into_ruby_error(
+        ruby,
+        error,
+        root_instance,
+        root_schema,
+        &message,
+        mask,
+        verbose,
+    ) {
         Ok(exc_value) => {
             if let Some(exc) = Exception::from_value(exc_value) {
                 exc.into()
@@ -511,7 +737,7 @@ fn handle_without_gvl_panic(ruby: &Ruby, err: Box<dyn std::any::Any + Send>) ->
 /// # Safety
 /// Caller must ensure the closure does not interact with Ruby.
 #[allow(unsafe_code)]
-unsafe fn without_gvl<F, R>(f: F) -> Result<R, Box<dyn std::any::Any + Send>>
+pub(crate) unsafe fn without_gvl<F, R>(f: F) -> Result<R, Box<dyn std::any::Any + Send>>
 where
     F: FnMut() -> R,
 {
@@ -546,6 +772,131 @@ where
     unsafe { payload.result.assume_init() }
 }
 
+/// Number of worker threads fanned out to by the batch `valid_all?`/`each_error_for`/
+/// `evaluate_all` APIs, one per available CPU (falling back to a single thread if
+/// that can't be read).
+fn batch_worker_count() -> usize {
+    std::thread::available_parallelism()
+        .map(std::num::NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// Runs `validator.is_valid` over every instance, fanning the batch across
+/// `batch_worker_count()` OS threads.
+///
+/// `jsonschema::Validator` is immutable once built and safe to share by
+/// reference across threads, so each worker validates its own slice against
+/// the same `&Validator` without cloning it. Must only be called without the
+/// GVL held (callers already guarantee this by running it inside [`without_gvl`]),
+/// since spawned threads never touch Ruby objects.
+fn valid_all_batch(
+    validator: &jsonschema::Validator,
+    instances: &[serde_json::Value],
+) -> Vec<bool> {
+    let worker_count = batch_worker_count().min(instances.len()).max(1);
+    let chunk_size = (instances.len() + worker_count - 1) / worker_count;
+    if chunk_size == 0 {
+        return Vec::new();
+    }
+    std::thread::scope(|scope| {
+        instances
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|instance| validator.is_valid(instance))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(results) => results,
+                Err(payload) => panic::resume_unwind(payload),
+            })
+            .collect()
+    })
+}
+
+/// Runs `validator.iter_errors` over every instance, fanning the batch across
+/// `batch_worker_count()` OS threads. See [`valid_all_batch`] for the
+/// thread-safety rationale; the same applies here.
+fn each_error_for_batch<'a>(
+    validator: &jsonschema::Validator,
+    instances: &'a [serde_json::Value],
+) -> Vec<Vec<jsonschema::ValidationError<'a>>> {
+    let worker_count = batch_worker_count().min(instances.len()).max(1);
+    let chunk_size = (instances.len() + worker_count - 1) / worker_count;
+    if chunk_size == 0 {
+        return Vec::new();
+    }
+    std::thread::scope(|scope| {
+        instances
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|instance| validator.iter_errors(instance).collect::<Vec<_>>())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(results) => results,
+                Err(payload) => panic::resume_unwind(payload),
+            })
+            .collect()
+    })
+}
+
+/// Runs `validator.evaluate` over every instance, fanning the batch across
+/// `batch_worker_count()` OS threads. See [`valid_all_batch`] for the
+/// thread-safety rationale; the same applies here. `jsonschema::Evaluation` owns
+/// its contents rather than borrowing from the instance, so unlike
+/// [`each_error_for_batch`] the result needs no lifetime tied to `instances`.
+fn evaluate_all_batch(
+    validator: &jsonschema::Validator,
+    instances: &[serde_json::Value],
+) -> Vec<jsonschema::Evaluation> {
+    let worker_count = batch_worker_count().min(instances.len()).max(1);
+    let chunk_size = (instances.len() + worker_count - 1) / worker_count;
+    if chunk_size == 0 {
+        return Vec::new();
+    }
+    std::thread::scope(|scope| {
+        instances
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|instance| validator.evaluate(instance))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .flat_map(|handle| match handle.join() {
+                Ok(results) => results,
+                Err(payload) => panic::resume_unwind(payload),
+            })
+            .collect()
+    })
+}
+
+fn ruby_array_to_json_values(
+    ruby: &Ruby,
+    instances: RArray,
+) -> Result<Vec<serde_json::Value>, Error> {
+    instances
+        .into_iter()
+        .map(|item| to_value(ruby, item))
+        .collect::<Result<_, _>>()
+}
+
 /// Wrapper around `jsonschema::Validator`.
 ///
 /// Holds GC-protection state for Ruby callbacks (format checkers, custom keywords,
@@ -556,6 +907,10 @@ where
 pub struct Validator {
     validator: jsonschema::Validator,
     mask: Option<String>,
+    /// Retained so `verbose`-gated errors can resolve their `schema_path` pointer
+    /// into the original schema document (the compiled validator discards it).
+    schema_json: serde_json::Value,
+    verbose: bool,
     has_ruby_callbacks: bool,
     /// Marked during Ruby's GC mark phase to keep runtime callbacks alive.
     callback_roots: CallbackRoots,
@@ -584,10 +939,14 @@ impl Validator {
     }
 
     #[allow(unsafe_code)]
-    fn is_valid(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<bool, Error> {
+    fn is_valid(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<bool, Error> {
+        let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+        let (instance,) = parsed_args.required;
+        let context = options::extract_context(ruby, parsed_args.keywords)?;
         let json_instance = to_value(ruby, instance)?;
 
         if rb_self.has_ruby_callbacks {
+            let _context = ContextGuard::new(context);
             let result = catch_unwind_silent(AssertUnwindSafe(|| {
                 rb_self.validator.is_valid(&json_instance)
             }));
@@ -605,10 +964,14 @@ impl Validator {
     }
 
     #[allow(unsafe_code)]
-    fn validate(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<(), Error> {
+    fn validate(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<(), Error> {
+        let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+        let (instance,) = parsed_args.required;
+        let context = options::extract_context(ruby, parsed_args.keywords)?;
         let json_instance = to_value(ruby, instance)?;
 
         if rb_self.has_ruby_callbacks {
+            let _context = ContextGuard::new(context);
             let result = catch_unwind_silent(AssertUnwindSafe(|| {
                 rb_self.validator.validate(&json_instance)
             }));
@@ -618,7 +981,9 @@ impl Validator {
                     ruby,
                     error,
                     Some(&json_instance),
+                    Some(&rb_self.schema_json),
                     rb_self.mask.as_deref(),
+                    rb_self.verbose,
                 )),
                 Err(err) => Err(handle_callback_panic(ruby, err)),
             }
@@ -630,7 +995,9 @@ impl Validator {
                     ruby,
                     error,
                     Some(&json_instance),
+                    Some(&rb_self.schema_json),
                     rb_self.mask.as_deref(),
+                    rb_self.verbose,
                 )),
                 Err(err) => Err(handle_without_gvl_panic(ruby, err)),
             }
@@ -638,12 +1005,16 @@ impl Validator {
     }
 
     #[allow(unsafe_code)]
-    fn iter_errors(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<Value, Error> {
+    fn iter_errors(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<Value, Error> {
+        let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+        let (instance,) = parsed_args.required;
+        let context = options::extract_context(ruby, parsed_args.keywords)?;
         let json_instance = to_value(ruby, instance)?;
 
         if ruby.block_given() {
             // Lazy path: yield errors one at a time to the block
             if rb_self.has_ruby_callbacks {
+                let _context = ContextGuard::new(context);
                 let mut iter = rb_self.validator.iter_errors(&json_instance);
                 loop {
                     let result = catch_unwind_silent(AssertUnwindSafe(|| iter.next()));
@@ -653,7 +1024,9 @@ impl Validator {
                                 ruby,
                                 error,
                                 Some(&json_instance),
+                                Some(&rb_self.schema_json),
                                 rb_self.mask.as_deref(),
+                                rb_self.verbose,
                             )?;
                             ruby.yield_value::<Value, Value>(ruby_error)?;
                         }
@@ -667,7 +1040,9 @@ impl Validator {
                         ruby,
                         error,
                         Some(&json_instance),
+                        Some(&rb_self.schema_json),
                         rb_self.mask.as_deref(),
+                        rb_self.verbose,
                     )?;
                     ruby.yield_value::<Value, Value>(ruby_error)?;
                 }
@@ -675,6 +1050,7 @@ impl Validator {
             Ok(ruby.qnil().as_value())
         } else if rb_self.has_ruby_callbacks {
             // Eager path with callbacks
+            let _context = ContextGuard::new(context);
             let result = catch_unwind_silent(AssertUnwindSafe(|| {
                 rb_self
                     .validator
@@ -689,7 +1065,9 @@ impl Validator {
                             ruby,
                             e,
                             Some(&json_instance),
+                            Some(&rb_self.schema_json),
                             rb_self.mask.as_deref(),
+                            rb_self.verbose,
                         )?)?;
                     }
                     Ok(arr.as_value())
@@ -716,7 +1094,9 @@ impl Validator {
                     ruby,
                     e,
                     Some(&json_instance),
+                    Some(&rb_self.schema_json),
                     rb_self.mask.as_deref(),
+                    rb_self.verbose,
                 )?)?;
             }
             Ok(arr.as_value())
@@ -724,15 +1104,19 @@ impl Validator {
     }
 
     #[allow(unsafe_code)]
-    fn evaluate(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<Evaluation, Error> {
+    fn evaluate(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<Evaluation, Error> {
+        let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+        let (instance,) = parsed_args.required;
+        let context = options::extract_context(ruby, parsed_args.keywords)?;
         let json_instance = to_value(ruby, instance)?;
 
         if rb_self.has_ruby_callbacks {
+            let _context = ContextGuard::new(context);
             let result = catch_unwind_silent(AssertUnwindSafe(|| {
                 rb_self.validator.evaluate(&json_instance)
             }));
             match result {
-                Ok(output) => Ok(Evaluation::new(output)),
+                Ok(output) => Ok(Evaluation::new(output, json_instance)),
                 Err(err) => Err(handle_callback_panic(ruby, err)),
             }
         } else {
@@ -742,10 +1126,127 @@ impl Validator {
                 Ok(output) => output,
                 Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
             };
-            Ok(Evaluation::new(output))
+            Ok(Evaluation::new(output, json_instance))
         }
     }
 
+    /// Validates every instance in `instances`, returning a Ruby array of booleans
+    /// in the same order. Converts the whole array to `Vec<serde_json::Value>` while
+    /// the GVL is held, then releases it once for the entire batch instead of once
+    /// per instance, fanning the validation work itself across threads.
+    ///
+    /// Falls back to the serial, GVL-held path whenever `has_ruby_callbacks` is set,
+    /// since Ruby format checkers/custom keywords cannot run off-thread.
+    #[allow(unsafe_code)]
+    fn valid_all(ruby: &Ruby, rb_self: &Self, instances: RArray) -> Result<Value, Error> {
+        let json_instances = ruby_array_to_json_values(ruby, instances)?;
+
+        let results = if rb_self.has_ruby_callbacks {
+            let result = catch_unwind_silent(AssertUnwindSafe(|| {
+                json_instances
+                    .iter()
+                    .map(|instance| rb_self.validator.is_valid(instance))
+                    .collect::<Vec<_>>()
+            }));
+            match result {
+                Ok(results) => results,
+                Err(err) => return Err(handle_callback_panic(ruby, err)),
+            }
+        } else {
+            // SAFETY: validation is pure Rust with no Ruby callbacks
+            match unsafe { without_gvl(|| valid_all_batch(&rb_self.validator, &json_instances)) } {
+                Ok(results) => results,
+                Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
+            }
+        };
+
+        let arr = ruby.ary_new_capa(results.len());
+        for valid in results {
+            arr.push(valid)?;
+        }
+        Ok(arr.as_value())
+    }
+
+    /// Validates every instance in `instances`, returning a Ruby array of arrays of
+    /// errors in the same order (empty for a valid instance). See [`Self::valid_all`]
+    /// for the batching and GVL-release strategy, which this mirrors.
+    #[allow(unsafe_code)]
+    fn each_error_for(ruby: &Ruby, rb_self: &Self, instances: RArray) -> Result<Value, Error> {
+        let json_instances = ruby_array_to_json_values(ruby, instances)?;
+
+        let results = if rb_self.has_ruby_callbacks {
+            let result = catch_unwind_silent(AssertUnwindSafe(|| {
+                json_instances
+                    .iter()
+                    .map(|instance| rb_self.validator.iter_errors(instance).collect::<Vec<_>>())
+                    .collect::<Vec<_>>()
+            }));
+            match result {
+                Ok(results) => results,
+                Err(err) => return Err(handle_callback_panic(ruby, err)),
+            }
+        } else {
+            // SAFETY: validation is pure Rust with no Ruby callbacks
+            match unsafe {
+                without_gvl(|| each_error_for_batch(&rb_self.validator, &json_instances))
+            } {
+                Ok(results) => results,
+                Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
+            }
+        };
+
+        let arr = ruby.ary_new_capa(results.len());
+        for (instance, errors) in json_instances.iter().zip(results) {
+            let errors_arr = ruby.ary_new_capa(errors.len());
+            for error in errors {
+                errors_arr.push(to_ruby_error_value(
+                    ruby,
+                    error,
+                    Some(instance),
+                    Some(&rb_self.schema_json),
+                    rb_self.mask.as_deref(),
+                    rb_self.verbose,
+                )?)?;
+            }
+            arr.push(errors_arr.as_value())?;
+        }
+        Ok(arr.as_value())
+    }
+
+    /// Evaluates every instance in `instances`, returning a Ruby array of
+    /// `Evaluation` objects in the same order. See [`Self::valid_all`] for the
+    /// batching and GVL-release strategy, which this mirrors.
+    #[allow(unsafe_code)]
+    fn evaluate_all(ruby: &Ruby, rb_self: &Self, instances: RArray) -> Result<Value, Error> {
+        let json_instances = ruby_array_to_json_values(ruby, instances)?;
+
+        let outputs = if rb_self.has_ruby_callbacks {
+            let result = catch_unwind_silent(AssertUnwindSafe(|| {
+                json_instances
+                    .iter()
+                    .map(|instance| rb_self.validator.evaluate(instance))
+                    .collect::<Vec<_>>()
+            }));
+            match result {
+                Ok(outputs) => outputs,
+                Err(err) => return Err(handle_callback_panic(ruby, err)),
+            }
+        } else {
+            // SAFETY: validation is pure Rust with no Ruby callbacks
+            match unsafe { without_gvl(|| evaluate_all_batch(&rb_self.validator, &json_instances)) }
+            {
+                Ok(outputs) => outputs,
+                Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
+            }
+        };
+
+        let arr = ruby.ary_new_capa(outputs.len());
+        for (output, instance) in outputs.into_iter().zip(json_instances) {
+            arr.push(Evaluation::new(output, instance))?;
+        }
+        Ok(arr.as_value())
+    }
+
     fn inspect(&self) -> String {
         let draft = match self.validator.draft() {
             jsonschema::Draft::Draft4 => "Draft4",
@@ -782,6 +1283,8 @@ fn validator_for(ruby: &Ruby, args: &[Value]) -> Result<Validator, Error> {
     Ok(Validator {
         validator,
         mask: parsed.mask,
+        schema_json: json_schema,
+        verbose: parsed.verbose,
         has_ruby_callbacks,
         callback_roots,
         _compilation_roots: compilation_roots,
@@ -813,6 +1316,7 @@ fn is_valid(ruby: &Ruby, args: &[Value]) -> Result<bool, Error> {
 
     if has_ruby_callbacks {
         let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+        let _context = ContextGuard::new(parsed.context);
         let result = catch_unwind_silent(AssertUnwindSafe(|| validator.is_valid(&json_instance)));
         match result {
             Ok(valid) => Ok(valid),
@@ -827,6 +1331,59 @@ fn is_valid(ruby: &Ruby, args: &[Value]) -> Result<bool, Error> {
     }
 }
 
+/// Module-level `JSONSchema.valid_all?(schema, instances)`: builds the validator once
+/// and batch-validates `instances` against it. See [`Validator::valid_all`] for the
+/// batching and GVL-release strategy.
+#[allow(unsafe_code)]
+fn valid_all(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    let parsed_args = scan_args::<(Value, RArray), (), (), (), _, ()>(args)?;
+    let (schema, instances) = parsed_args.required;
+    let kw = extract_kwargs(ruby, parsed_args.keywords)?;
+
+    let json_schema = to_schema_value(ruby, schema)?;
+    let json_instances = ruby_array_to_json_values(ruby, instances)?;
+    let parsed = build_parsed_options(ruby, kw, None)?;
+    let has_ruby_callbacks = parsed.has_ruby_callbacks;
+    let BuiltValidator {
+        validator,
+        callback_roots,
+        compilation_roots: _compilation_roots,
+    } = build_validator(
+        ruby,
+        parsed.options,
+        parsed.retriever,
+        parsed.callback_roots,
+        parsed.compilation_roots,
+        &json_schema,
+    )?;
+
+    let results = if has_ruby_callbacks {
+        let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+        let result = catch_unwind_silent(AssertUnwindSafe(|| {
+            json_instances
+                .iter()
+                .map(|instance| validator.is_valid(instance))
+                .collect::<Vec<_>>()
+        }));
+        match result {
+            Ok(results) => results,
+            Err(err) => return Err(handle_callback_panic(ruby, err)),
+        }
+    } else {
+        // SAFETY: validation is pure Rust with no Ruby callbacks
+        match unsafe { without_gvl(|| valid_all_batch(&validator, &json_instances)) } {
+            Ok(results) => results,
+            Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
+        }
+    };
+
+    let arr = ruby.ary_new_capa(results.len());
+    for valid in results {
+        arr.push(valid)?;
+    }
+    Ok(arr.as_value())
+}
+
 #[allow(unsafe_code)]
 fn validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
     let parsed_args = scan_args::<(Value, Value), (), (), (), _, ()>(args)?;
@@ -852,6 +1409,7 @@ fn validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
 
     if has_ruby_callbacks {
         let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+        let _context = ContextGuard::new(parsed.context);
         let result = catch_unwind_silent(AssertUnwindSafe(|| validator.validate(&json_instance)));
         match result {
             Ok(Ok(())) => Ok(()),
@@ -859,7 +1417,9 @@ fn validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
                 ruby,
                 error,
                 Some(&json_instance),
+                Some(&json_schema),
                 parsed.mask.as_deref(),
+                parsed.verbose,
             )),
             Err(err) => Err(handle_callback_panic(ruby, err)),
         }
@@ -871,7 +1431,9 @@ fn validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
                 ruby,
                 error,
                 Some(&json_instance),
+                Some(&json_schema),
                 parsed.mask.as_deref(),
+                parsed.verbose,
             )),
             Err(err) => Err(handle_without_gvl_panic(ruby, err)),
         }
@@ -905,6 +1467,7 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
         // Lazy path: yield errors one at a time to the block
         if has_ruby_callbacks {
             let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+            let _context = ContextGuard::new(parsed.context);
             let mut iter = validator.iter_errors(&json_instance);
             loop {
                 let result = catch_unwind_silent(AssertUnwindSafe(|| iter.next()));
@@ -914,7 +1477,9 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
                             ruby,
                             error,
                             Some(&json_instance),
+                            Some(&json_schema),
                             parsed.mask.as_deref(),
+                            parsed.verbose,
                         )?;
                         ruby.yield_value::<Value, Value>(ruby_error)?;
                     }
@@ -924,8 +1489,14 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
             }
         } else {
             for error in validator.iter_errors(&json_instance) {
-                let ruby_error =
-                    to_ruby_error_value(ruby, error, Some(&json_instance), parsed.mask.as_deref())?;
+                let ruby_error = to_ruby_error_value(
+                    ruby,
+                    error,
+                    Some(&json_instance),
+                    Some(&json_schema),
+                    parsed.mask.as_deref(),
+                    parsed.verbose,
+                )?;
                 ruby.yield_value::<Value, Value>(ruby_error)?;
             }
         }
@@ -933,6 +1504,7 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
     } else if has_ruby_callbacks {
         // Eager path with callbacks
         let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+        let _context = ContextGuard::new(parsed.context);
         let result = catch_unwind_silent(AssertUnwindSafe(|| {
             validator.iter_errors(&json_instance).collect::<Vec<_>>()
         }));
@@ -944,7 +1516,9 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
                         ruby,
                         e,
                         Some(&json_instance),
+                        Some(&json_schema),
                         parsed.mask.as_deref(),
+                        parsed.verbose,
                     )?)?;
                 }
                 Ok(arr.as_value())
@@ -966,7 +1540,9 @@ fn each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
                 ruby,
                 e,
                 Some(&json_instance),
+                Some(&json_schema),
                 parsed.mask.as_deref(),
+                parsed.verbose,
             )?)?;
         }
         Ok(arr.as_value())
@@ -998,9 +1574,10 @@ fn evaluate(ruby: &Ruby, args: &[Value]) -> Result<Evaluation, Error> {
 
     if has_ruby_callbacks {
         let _callback_roots = CallbackRootGuard::new(ruby, &callback_roots);
+        let _context = ContextGuard::new(parsed.context);
         let result = catch_unwind_silent(AssertUnwindSafe(|| validator.evaluate(&json_instance)));
         match result {
-            Ok(output) => Ok(Evaluation::new(output)),
+            Ok(output) => Ok(Evaluation::new(output, json_instance)),
             Err(err) => Err(handle_callback_panic(ruby, err)),
         }
     } else {
@@ -1009,7 +1586,7 @@ fn evaluate(ruby: &Ruby, args: &[Value]) -> Result<Evaluation, Error> {
             Ok(output) => output,
             Err(err) => return Err(handle_without_gvl_panic(ruby, err)),
         };
-        Ok(Evaluation::new(output))
+        Ok(Evaluation::new(output, json_instance))
     }
 }
 
@@ -1052,6 +1629,8 @@ macro_rules! define_draft_validator {
                     inner: Validator {
                         validator,
                         mask: parsed.mask,
+                        schema_json: json_schema,
+                        verbose: parsed.verbose,
                         has_ruby_callbacks,
                         callback_roots,
                         _compilation_roots: compilation_roots,
@@ -1059,20 +1638,36 @@ macro_rules! define_draft_validator {
                 })
             }
 
-            fn is_valid(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<bool, Error> {
-                Validator::is_valid(ruby, &rb_self.inner, instance)
+            fn is_valid(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<bool, Error> {
+                Validator::is_valid(ruby, &rb_self.inner, args)
+            }
+
+            fn validate(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<(), Error> {
+                Validator::validate(ruby, &rb_self.inner, args)
             }
 
-            fn validate(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<(), Error> {
-                Validator::validate(ruby, &rb_self.inner, instance)
+            fn iter_errors(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<Value, Error> {
+                Validator::iter_errors(ruby, &rb_self.inner, args)
             }
 
-            fn iter_errors(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<Value, Error> {
-                Validator::iter_errors(ruby, &rb_self.inner, instance)
+            fn evaluate(ruby: &Ruby, rb_self: &Self, args: &[Value]) -> Result<Evaluation, Error> {
+                Validator::evaluate(ruby, &rb_self.inner, args)
             }
 
-            fn evaluate(ruby: &Ruby, rb_self: &Self, instance: Value) -> Result<Evaluation, Error> {
-                Validator::evaluate(ruby, &rb_self.inner, instance)
+            fn valid_all(ruby: &Ruby, rb_self: &Self, instances: RArray) -> Result<Value, Error> {
+                Validator::valid_all(ruby, &rb_self.inner, instances)
+            }
+
+            fn each_error_for(
+                ruby: &Ruby,
+                rb_self: &Self,
+                instances: RArray,
+            ) -> Result<Value, Error> {
+                Validator::each_error_for(ruby, &rb_self.inner, instances)
+            }
+
+            fn evaluate_all(ruby: &Ruby, rb_self: &Self, instances: RArray) -> Result<Value, Error> {
+                Validator::evaluate_all(ruby, &rb_self.inner, instances)
             }
 
             fn inspect(&self) -> String {
@@ -1108,20 +1703,173 @@ define_draft_validator!(
     jsonschema::Draft::Draft202012
 );
 
+/// Layers `Meta.add_format`-registered checkers onto a `jsonschema::meta::options()`
+/// builder. Unlike keywords, registered format checkers need no per-call GC
+/// rooting beyond what [`meta_registry::add_format`] already did permanently.
+macro_rules! apply_meta_formats {
+    ($opts:expr, $entries:expr) => {
+        for (name, checker) in $entries {
+            $opts = $opts.with_format(name, move |value: &str| checker.check(value));
+        }
+    };
+}
+
+/// Layers `Meta.add_keyword`-registered factories onto a `jsonschema::meta::options()`
+/// builder, mirroring `make_options_from_kwargs`'s `keywords:` handling in
+/// `options.rs`. Each instantiated keyword is rooted in `$compilation_roots`,
+/// which the caller must keep alive for as long as the resulting validator is
+/// used (the same `CompilationRoots`/`CallbackRootGuard` split documented on
+/// [`ParsedOptions`](options::ParsedOptions)).
+macro_rules! apply_meta_keywords {
+    ($opts:expr, $entries:expr, $compilation_roots:expr) => {
+        for (name, factory) in $entries {
+            let compilation_roots_for_keyword = Arc::clone(&$compilation_roots);
+            let name_err = name.clone();
+            $opts = $opts.with_keyword(
+                name,
+                move |parent: &serde_json::Map<String, serde_json::Value>,
+                      value: &serde_json::Value,
+                      path: jsonschema::paths::Location| {
+                    let inner_ruby = Ruby::get().expect("Ruby VM should be initialized");
+
+                    if factory.stateless {
+                        return Ok(options::boxed_ruby_keyword(factory.class, factory.has_valid));
+                    }
+
+                    let rb_schema = ser::map_to_ruby(&inner_ruby, parent).map_err(|e| {
+                        jsonschema::ValidationError::custom(format!(
+                            "Failed to convert schema to Ruby: {e}"
+                        ))
+                    })?;
+                    let rb_value = ser::value_to_ruby(&inner_ruby, value).map_err(|e| {
+                        jsonschema::ValidationError::custom(format!(
+                            "Failed to convert keyword value to Ruby: {e}"
+                        ))
+                    })?;
+                    let rb_path =
+                        inner_ruby.ary_from_iter(path.iter().map(|segment| match segment {
+                            LocationSegment::Property(p) => inner_ruby.into_value(p.as_ref()),
+                            LocationSegment::Index(i) => inner_ruby.into_value(i),
+                        }));
+
+                    let class = inner_ruby.get_inner(factory.class);
+                    let instance: Result<Value, _> =
+                        class.funcall("new", (rb_schema, rb_value, rb_path));
+
+                    match instance {
+                        Ok(inst) => {
+                            let opaque_inst = Opaque::from(inst);
+                            compilation_roots_for_keyword
+                                .add(opaque_inst)
+                                .map_err(|()| {
+                                    jsonschema::ValidationError::custom(
+                                        "Compilation callback root storage is poisoned",
+                                    )
+                                })?;
+                            Ok(options::boxed_ruby_keyword(opaque_inst, factory.has_valid))
+                        }
+                        Err(e) => Err(jsonschema::ValidationError::custom(format!(
+                            "Failed to instantiate keyword class '{name_err}': {e}"
+                        ))),
+                    }
+                },
+            );
+        }
+    };
+}
+
+/// Whether `kind` is the metaschema rejecting a schema-object key it doesn't
+/// recognize (`additionalProperties`/`unevaluatedProperties`), the failure
+/// mode `ignore_unknown_keywords:` suppresses so vendor extensions (`x-*`,
+/// tooling annotations) don't need to be stripped before meta-validation.
+fn is_unknown_keyword_error(kind: &jsonschema::error::ValidationErrorKind) -> bool {
+    matches!(
+        kind,
+        jsonschema::error::ValidationErrorKind::AdditionalProperties { .. }
+            | jsonschema::error::ValidationErrorKind::UnevaluatedProperties { .. }
+    )
+}
+
 fn meta_is_valid(ruby: &Ruby, args: &[Value]) -> Result<bool, Error> {
     use magnus::scan_args::get_kwargs;
     let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
     let (schema,) = parsed_args.required;
-    let kw: magnus::scan_args::KwArgs<(), (Option<Option<&Registry>>,), ()> =
-        get_kwargs(parsed_args.keywords, &[], &[*options::KW_REGISTRY])?;
+    let kw: magnus::scan_args::KwArgs<
+        (),
+        (
+            Option<Option<&Registry>>,
+            Option<Option<Value>>,
+            Option<Option<Value>>,
+            Option<Option<bool>>,
+        ),
+        (),
+    > = get_kwargs(
+        parsed_args.keywords,
+        &[],
+        &[
+            *options::KW_REGISTRY,
+            *options::KW_DRAFT,
+            *options::KW_RETRIEVER,
+            *options::KW_IGNORE_UNKNOWN_KEYWORDS,
+        ],
+    )?;
     let registry = kw.optional.0.flatten();
+    let draft = match kw.optional.1.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    let retriever_val = kw.optional.2.flatten().filter(|val| !val.is_nil());
+    let retriever = match retriever_val {
+        Some(val) => make_registry_retriever(ruby, val, None, &NativeHttpConfig::default())?,
+        None => None,
+    };
+    let ignore_unknown_keywords = kw.optional.3.flatten().unwrap_or(false);
+    let _retriever_root = MetaRetrieverRootGuard::new(retriever_val);
 
     let json_schema = to_schema_value(ruby, schema)?;
 
-    let result = if let Some(reg) = registry {
-        jsonschema::meta::options()
-            .with_registry(reg.inner.clone())
-            .validate(&json_schema)
+    if ignore_unknown_keywords {
+        let (validator, _compilation_roots) = meta_build_validator(ruby, registry, draft, retriever)?;
+        return match validator
+            .iter_errors(&json_schema)
+            .find(|error| !is_unknown_keyword_error(error.kind()))
+        {
+            None => Ok(true),
+            Some(error) => {
+                if let jsonschema::error::ValidationErrorKind::Referencing(err) = error.kind() {
+                    if let Some(message) = retriever_error_message(err) {
+                        return Err(Error::new(ruby.exception_arg_error(), message));
+                    }
+                    return Err(referencing_error(ruby, err.to_string()));
+                }
+                Ok(false)
+            }
+        };
+    }
+
+    let meta_formats = meta_registry::formats_for(draft);
+    let meta_keywords = meta_registry::keywords_for(draft);
+    let compilation_roots = Arc::new(CompilationRoots::default());
+
+    let result = if registry.is_some()
+        || draft.is_some()
+        || retriever.is_some()
+        || !meta_formats.is_empty()
+        || !meta_keywords.is_empty()
+    {
+        let mut meta_options = jsonschema::meta::options();
+        if let Some(reg) = registry {
+            meta_options = meta_options.with_registry(reg.inner.clone());
+        }
+        if let Some(draft) = draft {
+            meta_options = meta_options.with_draft(draft);
+        }
+        apply_meta_formats!(meta_options, meta_formats);
+        apply_meta_keywords!(meta_options, meta_keywords, compilation_roots);
+        match retriever {
+            Some(ret) => meta_options.with_retriever(ret).validate(&json_schema),
+            None => meta_options.validate(&json_schema),
+        }
     } else {
         jsonschema::meta::validate(&json_schema)
     };
@@ -1130,6 +1878,9 @@ fn meta_is_valid(ruby: &Ruby, args: &[Value]) -> Result<bool, Error> {
         Ok(()) => Ok(true),
         Err(error) => {
             if let jsonschema::error::ValidationErrorKind::Referencing(err) = error.kind() {
+                if let Some(message) = retriever_error_message(err) {
+                    return Err(Error::new(ruby.exception_arg_error(), message));
+                }
                 return Err(referencing_error(ruby, err.to_string()));
             }
             Ok(false)
@@ -1141,16 +1892,89 @@ fn meta_validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
     use magnus::scan_args::get_kwargs;
     let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
     let (schema,) = parsed_args.required;
-    let kw: magnus::scan_args::KwArgs<(), (Option<Option<&Registry>>,), ()> =
-        get_kwargs(parsed_args.keywords, &[], &[*options::KW_REGISTRY])?;
+    let kw: magnus::scan_args::KwArgs<
+        (),
+        (
+            Option<Option<&Registry>>,
+            Option<Option<Value>>,
+            Option<Option<Value>>,
+            Option<Option<bool>>,
+        ),
+        (),
+    > = get_kwargs(
+        parsed_args.keywords,
+        &[],
+        &[
+            *options::KW_REGISTRY,
+            *options::KW_DRAFT,
+            *options::KW_RETRIEVER,
+            *options::KW_IGNORE_UNKNOWN_KEYWORDS,
+        ],
+    )?;
     let registry = kw.optional.0.flatten();
+    let draft = match kw.optional.1.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    let retriever_val = kw.optional.2.flatten().filter(|val| !val.is_nil());
+    let retriever = match retriever_val {
+        Some(val) => make_registry_retriever(ruby, val, None, &NativeHttpConfig::default())?,
+        None => None,
+    };
+    let ignore_unknown_keywords = kw.optional.3.flatten().unwrap_or(false);
+    let _retriever_root = MetaRetrieverRootGuard::new(retriever_val);
 
     let json_schema = to_schema_value(ruby, schema)?;
 
-    let result = if let Some(reg) = registry {
-        jsonschema::meta::options()
-            .with_registry(reg.inner.clone())
-            .validate(&json_schema)
+    if ignore_unknown_keywords {
+        let (validator, _compilation_roots) = meta_build_validator(ruby, registry, draft, retriever)?;
+        return match validator
+            .iter_errors(&json_schema)
+            .find(|error| !is_unknown_keyword_error(error.kind()))
+        {
+            None => Ok(()),
+            Some(error) => {
+                if let jsonschema::error::ValidationErrorKind::Referencing(err) = error.kind() {
+                    if let Some(message) = retriever_error_message(err) {
+                        return Err(Error::new(ruby.exception_arg_error(), message));
+                    }
+                    return Err(referencing_error(ruby, err.to_string()));
+                }
+                Err(raise_validation_error(
+                    ruby,
+                    error,
+                    Some(&json_schema),
+                    None,
+                    None,
+                    false,
+                ))
+            }
+        };
+    }
+
+    let meta_formats = meta_registry::formats_for(draft);
+    let meta_keywords = meta_registry::keywords_for(draft);
+    let compilation_roots = Arc::new(CompilationRoots::default());
+
+    let result = if registry.is_some()
+        || draft.is_some()
+        || retriever.is_some()
+        || !meta_formats.is_empty()
+        || !meta_keywords.is_empty()
+    {
+        let mut meta_options = jsonschema::meta::options();
+        if let Some(reg) = registry {
+            meta_options = meta_options.with_registry(reg.inner.clone());
+        }
+        if let Some(draft) = draft {
+            meta_options = meta_options.with_draft(draft);
+        }
+        apply_meta_formats!(meta_options, meta_formats);
+        apply_meta_keywords!(meta_options, meta_keywords, compilation_roots);
+        match retriever {
+            Some(ret) => meta_options.with_retriever(ret).validate(&json_schema),
+            None => meta_options.validate(&json_schema),
+        }
     } else {
         jsonschema::meta::validate(&json_schema)
     };
@@ -1159,6 +1983,9 @@ fn meta_validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
         Ok(()) => Ok(()),
         Err(error) => {
             if let jsonschema::error::ValidationErrorKind::Referencing(err) = error.kind() {
+                if let Some(message) = retriever_error_message(err) {
+                    return Err(Error::new(ruby.exception_arg_error(), message));
+                }
                 return Err(referencing_error(ruby, err.to_string()));
             }
             Err(raise_validation_error(
@@ -1166,11 +1993,272 @@ fn meta_validate(ruby: &Ruby, args: &[Value]) -> Result<(), Error> {
                 error,
                 Some(&json_schema),
                 None,
+                None,
+                false,
             ))
         }
     }
 }
 
+/// Builds the meta-schema validator (optionally against a custom `registry`,
+/// a forced `draft`, and/or a `retriever` for `$ref`/`$schema` URIs the
+/// bundled drafts don't cover) as a real `jsonschema::Validator`, shared by
+/// `Meta.each_error` and `Meta.validate`, both of which need to iterate every
+/// violation rather than stopping at the first one the way
+/// `Meta.validate!`/`Meta.valid?` do.
+fn meta_build_validator(
+    ruby: &Ruby,
+    registry: Option<&Registry>,
+    draft: Option<jsonschema::Draft>,
+    retriever: Option<AnyRetriever>,
+) -> Result<(jsonschema::Validator, Arc<CompilationRoots>), Error> {
+    let mut meta_options = jsonschema::meta::options();
+    if let Some(reg) = registry {
+        meta_options = meta_options.with_registry(reg.inner.clone());
+    }
+    if let Some(draft) = draft {
+        meta_options = meta_options.with_draft(draft);
+    }
+    let compilation_roots = Arc::new(CompilationRoots::default());
+    apply_meta_formats!(meta_options, meta_registry::formats_for(draft));
+    apply_meta_keywords!(
+        meta_options,
+        meta_registry::keywords_for(draft),
+        compilation_roots
+    );
+    let validator = match retriever {
+        Some(ret) => meta_options.with_retriever(ret).build(),
+        None => meta_options.build(),
+    }
+    .map_err(|error| {
+        if let jsonschema::error::ValidationErrorKind::Referencing(err) = error.kind() {
+            if let Some(message) = retriever_error_message(err) {
+                Error::new(ruby.exception_arg_error(), message)
+            } else {
+                referencing_error(ruby, err.to_string())
+            }
+        } else {
+            Error::new(ruby.exception_arg_error(), error.to_string())
+        }
+    })?;
+    Ok((validator, compilation_roots))
+}
+
+/// `JSONSchema::Meta.each_error(schema, registry: nil)`: unlike `Meta.validate!`/
+/// `Meta.valid?`, which stop at the first violation, this builds the meta-schema
+/// validator as a real `jsonschema::Validator` and iterates every error against
+/// `schema` (treated as the "instance" being validated), so authoring tools can
+/// report everything wrong with a malformed schema in one pass.
+fn meta_each_error(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    use magnus::scan_args::get_kwargs;
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (schema,) = parsed_args.required;
+    let kw: magnus::scan_args::KwArgs<
+        (),
+        (
+            Option<Option<&Registry>>,
+            Option<Option<Value>>,
+            Option<Option<Value>>,
+        ),
+        (),
+    > = get_kwargs(
+        parsed_args.keywords,
+        &[],
+        &[*options::KW_REGISTRY, *options::KW_DRAFT, *options::KW_RETRIEVER],
+    )?;
+    let registry = kw.optional.0.flatten();
+    let draft = match kw.optional.1.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    let retriever_val = kw.optional.2.flatten().filter(|val| !val.is_nil());
+    let retriever = match retriever_val {
+        Some(val) => make_registry_retriever(ruby, val, None, &NativeHttpConfig::default())?,
+        None => None,
+    };
+    let _retriever_root = MetaRetrieverRootGuard::new(retriever_val);
+
+    let json_schema = to_schema_value(ruby, schema)?;
+    let (validator, _compilation_roots) = meta_build_validator(ruby, registry, draft, retriever)?;
+
+    if ruby.block_given() {
+        for error in validator.iter_errors(&json_schema) {
+            let ruby_error = to_ruby_error_value(ruby, error, Some(&json_schema), None, None, false)?;
+            ruby.yield_value::<Value, Value>(ruby_error)?;
+        }
+        Ok(ruby.qnil().as_value())
+    } else {
+        let errors = validator.iter_errors(&json_schema).collect::<Vec<_>>();
+        let arr = ruby.ary_new_capa(errors.len());
+        for error in errors {
+            arr.push(to_ruby_error_value(
+                ruby,
+                error,
+                Some(&json_schema),
+                None,
+                None,
+                false,
+            )?)?;
+        }
+        Ok(arr.as_value())
+    }
+}
+
+/// Recursively collects `(pointer, subschema, example_value)` triples for
+/// every `examples` array entry and `default` value reachable from `schema`,
+/// descending into the composition/applicator keywords common across drafts
+/// (`properties`, `patternProperties`, `additionalProperties`, `items`,
+/// `prefixItems`, `contains`, `$defs`, `definitions`, `allOf`, `anyOf`,
+/// `oneOf`, `not`, `if`/`then`/`else`). `pointer` locates the `examples`/
+/// `default` keyword itself, for reporting which example failed.
+///
+/// Each subschema is validated against standalone via
+/// `jsonschema::validator_for`, so a `$ref` inside one that points elsewhere
+/// in the document (rather than being self-contained) won't resolve — the
+/// same kind of scope limit `jsonschema-cli`'s format-checking walker
+/// documents for its own local-only `$ref` handling.
+fn collect_schema_examples(
+    schema: &serde_json::Value,
+    pointer: &str,
+    out: &mut Vec<(String, serde_json::Value, serde_json::Value)>,
+) {
+    let serde_json::Value::Object(object) = schema else {
+        return;
+    };
+
+    if let Some(serde_json::Value::Array(examples)) = object.get("examples") {
+        for (index, example) in examples.iter().enumerate() {
+            out.push((
+                format!("{pointer}/examples/{index}"),
+                schema.clone(),
+                example.clone(),
+            ));
+        }
+    }
+    if let Some(default) = object.get("default") {
+        out.push((format!("{pointer}/default"), schema.clone(), default.clone()));
+    }
+
+    for key in ["properties", "patternProperties", "$defs", "definitions"] {
+        if let Some(serde_json::Value::Object(map)) = object.get(key) {
+            for (name, sub) in map {
+                collect_schema_examples(sub, &format!("{pointer}/{key}/{name}"), out);
+            }
+        }
+    }
+    for key in [
+        "additionalProperties",
+        "items",
+        "contains",
+        "not",
+        "if",
+        "then",
+        "else",
+    ] {
+        if let Some(sub) = object.get(key) {
+            if sub.is_object() {
+                collect_schema_examples(sub, &format!("{pointer}/{key}"), out);
+            }
+        }
+    }
+    for key in ["allOf", "anyOf", "oneOf", "prefixItems"] {
+        if let Some(serde_json::Value::Array(items)) = object.get(key) {
+            for (index, sub) in items.iter().enumerate() {
+                collect_schema_examples(sub, &format!("{pointer}/{key}/{index}"), out);
+            }
+        }
+    }
+}
+
+/// `JSONSchema::Meta.validate(schema, registry: nil)`: a non-raising counterpart
+/// to `validate!` that always returns the full `Array` of structured
+/// `ValidationError`s (empty when `schema` satisfies its metaschema) instead of
+/// stopping at the first violation — the same collection `each_error` does when
+/// called without a block, exposed as a direct substitute for tooling that wants
+/// every metaschema problem from a single call rather than raising or iterating.
+///
+/// When `check_examples: true` and `schema` is itself valid, also compiles
+/// `schema` and checks every `examples`/`default` value embedded in it
+/// against the (sub)schema it appears under, mirroring the PGXN Meta test
+/// suite's practice of validating a schema's examples alongside the schema
+/// itself. Any mismatches are appended to the returned array as
+/// `{pointer:, error:}` hashes, where `pointer` locates the offending
+/// `examples`/`default` entry in `schema` and `error` is the `ValidationError`
+/// it failed with.
+fn meta_validate_all(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    use magnus::scan_args::get_kwargs;
+    let parsed_args = scan_args::<(Value,), (), (), (), _, ()>(args)?;
+    let (schema,) = parsed_args.required;
+    let kw: magnus::scan_args::KwArgs<
+        (),
+        (
+            Option<Option<&Registry>>,
+            Option<Option<Value>>,
+            Option<Option<Value>>,
+            Option<Option<bool>>,
+        ),
+        (),
+    > = get_kwargs(
+        parsed_args.keywords,
+        &[],
+        &[
+            *options::KW_REGISTRY,
+            *options::KW_DRAFT,
+            *options::KW_RETRIEVER,
+            *options::KW_CHECK_EXAMPLES,
+        ],
+    )?;
+    let registry = kw.optional.0.flatten();
+    let draft = match kw.optional.1.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    let retriever_val = kw.optional.2.flatten().filter(|val| !val.is_nil());
+    let retriever = match retriever_val {
+        Some(val) => make_registry_retriever(ruby, val, None, &NativeHttpConfig::default())?,
+        None => None,
+    };
+    let check_examples = kw.optional.3.flatten().unwrap_or(false);
+    let _retriever_root = MetaRetrieverRootGuard::new(retriever_val);
+
+    let json_schema = to_schema_value(ruby, schema)?;
+    let (validator, _compilation_roots) = meta_build_validator(ruby, registry, draft, retriever)?;
+
+    let errors = validator.iter_errors(&json_schema).collect::<Vec<_>>();
+    let schema_is_valid = errors.is_empty();
+    let arr = ruby.ary_new_capa(errors.len());
+    for error in errors {
+        arr.push(to_ruby_error_value(
+            ruby,
+            error,
+            Some(&json_schema),
+            None,
+            None,
+            false,
+        )?)?;
+    }
+
+    if check_examples && schema_is_valid {
+        let mut examples = Vec::new();
+        collect_schema_examples(&json_schema, "", &mut examples);
+        for (pointer, sub_schema, value) in examples {
+            let Ok(sub_validator) = jsonschema::validator_for(&sub_schema) else {
+                continue;
+            };
+            for error in sub_validator.iter_errors(&value) {
+                let ruby_error =
+                    to_ruby_error_value(ruby, error, Some(&value), Some(&sub_schema), None, false)?;
+                let entry = ruby.hash_new_capa(2);
+                entry.aset(ID_SYM_POINTER.to_symbol(), pointer.as_str())?;
+                entry.aset(ID_SYM_ERROR.to_symbol(), ruby_error)?;
+                arr.push(entry.as_value())?;
+            }
+        }
+    }
+
+    Ok(arr.as_value())
+}
+
 // ValidationError instance methods (defined from Rust, called on exception instances)
 
 fn validation_error_to_s(ruby: &Ruby, rb_self: Value) -> Result<Value, Error> {
@@ -1256,6 +2344,8 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         &*ID_SYM_INSTANCE_PATH_POINTER,
         &*ID_SYM_SCHEMA_PATH_POINTER,
         &*ID_SYM_EVALUATION_PATH_POINTER,
+        &*ID_SYM_DETAILED_MESSAGE,
+        &*ID_SYM_SCHEMA_FRAGMENT,
     ] {
         let _: Value = validation_error_rclass.funcall("attr_reader", (sym_id.to_symbol(),))?;
     }
@@ -1275,13 +2365,17 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_singleton_method("validate!", function!(validate, -1))?;
     module.define_singleton_method("each_error", function!(each_error, -1))?;
     module.define_singleton_method("evaluate", function!(evaluate, -1))?;
+    module.define_singleton_method("valid_all?", function!(valid_all, -1))?;
 
     // Validator class
     let validator_class = module.define_class("Validator", ruby.class_object())?;
-    validator_class.define_method("valid?", method!(Validator::is_valid, 1))?;
-    validator_class.define_method("validate!", method!(Validator::validate, 1))?;
-    validator_class.define_method("each_error", method!(Validator::iter_errors, 1))?;
-    validator_class.define_method("evaluate", method!(Validator::evaluate, 1))?;
+    validator_class.define_method("valid?", method!(Validator::is_valid, -1))?;
+    validator_class.define_method("validate!", method!(Validator::validate, -1))?;
+    validator_class.define_method("each_error", method!(Validator::iter_errors, -1))?;
+    validator_class.define_method("evaluate", method!(Validator::evaluate, -1))?;
+    validator_class.define_method("valid_all?", method!(Validator::valid_all, 1))?;
+    validator_class.define_method("each_error_for", method!(Validator::each_error_for, 1))?;
+    validator_class.define_method("evaluate_all", method!(Validator::evaluate_all, 1))?;
     validator_class.define_method("inspect", method!(Validator::inspect, 0))?;
 
     // Draft-specific validators
@@ -1289,10 +2383,13 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
         ($ruby:expr, $module:expr, $name:ident, $class_str:expr, $superclass:expr) => {
             let cls = $module.define_class($class_str, $superclass)?;
             cls.define_singleton_method("new", function!($name::new_impl, -1))?;
-            cls.define_method("valid?", method!($name::is_valid, 1))?;
-            cls.define_method("validate!", method!($name::validate, 1))?;
-            cls.define_method("each_error", method!($name::iter_errors, 1))?;
-            cls.define_method("evaluate", method!($name::evaluate, 1))?;
+            cls.define_method("valid?", method!($name::is_valid, -1))?;
+            cls.define_method("validate!", method!($name::validate, -1))?;
+            cls.define_method("each_error", method!($name::iter_errors, -1))?;
+            cls.define_method("evaluate", method!($name::evaluate, -1))?;
+            cls.define_method("valid_all?", method!($name::valid_all, 1))?;
+            cls.define_method("each_error_for", method!($name::each_error_for, 1))?;
+            cls.define_method("evaluate_all", method!($name::evaluate_all, 1))?;
             cls.define_method("inspect", method!($name::inspect, 0))?;
         };
     }
@@ -1344,6 +2441,82 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     let meta_module = module.define_module("Meta")?;
     meta_module.define_singleton_method("valid?", function!(meta_is_valid, -1))?;
     meta_module.define_singleton_method("validate!", function!(meta_validate, -1))?;
+    meta_module.define_singleton_method("each_error", function!(meta_each_error, -1))?;
+    meta_module.define_singleton_method("validate", function!(meta_validate_all, -1))?;
+    meta_module.define_singleton_method("add_format", function!(meta_add_format, -1))?;
+    meta_module.define_singleton_method("add_keyword", function!(meta_add_keyword, -1))?;
+
+    let content_encoding_module = module.define_module("ContentEncoding")?;
+    content_encoding_module
+        .define_singleton_method("decode", function!(content_encoding_decode, -1))?;
+    content_encoding_module
+        .define_singleton_method("register", function!(content_encoding_register, -1))?;
 
     Ok(())
 }
+
+/// `JSONSchema::Meta.add_format(name, draft: nil, &block)`: registers a named
+/// `format` checker (e.g. a regex-backed `"pg-identifier"`) that every
+/// subsequent `Meta.valid?`/`Meta.validate!`/`Meta.each_error`/`Meta.validate`
+/// call applies during meta-schema validation, alongside whatever the
+/// targeted draft already understands. See [`meta_registry::add_format`].
+fn meta_add_format(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    use magnus::scan_args::get_kwargs;
+    let parsed_args = scan_args::<(String,), (), (), (), _, ()>(args)?;
+    let (name,) = parsed_args.required;
+    let kw: magnus::scan_args::KwArgs<(), (Option<Option<Value>>,), ()> =
+        get_kwargs(parsed_args.keywords, &[], &[*options::KW_DRAFT])?;
+    let draft = match kw.optional.0.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    let callback = ruby
+        .block_proc()
+        .map_err(|_| Error::new(ruby.exception_arg_error(), "add_format requires a block"))?
+        .as_value();
+    meta_registry::add_format(ruby, name, draft, callback)?;
+    Ok(ruby.qnil().as_value())
+}
+
+/// `JSONSchema::Meta.add_keyword(name, klass, draft: nil)`: registers a custom
+/// keyword validator class that participates in meta-schema validation the
+/// same way `Validator.new(keywords: {...})` does for ordinary instance
+/// validation. See [`meta_registry::add_keyword`].
+fn meta_add_keyword(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    use magnus::scan_args::get_kwargs;
+    let parsed_args = scan_args::<(String, Value), (), (), (), _, ()>(args)?;
+    let (name, class) = parsed_args.required;
+    let kw: magnus::scan_args::KwArgs<(), (Option<Option<Value>>,), ()> =
+        get_kwargs(parsed_args.keywords, &[], &[*options::KW_DRAFT])?;
+    let draft = match kw.optional.0.flatten() {
+        Some(val) => Some(options::parse_draft_symbol(ruby, val)?),
+        None => None,
+    };
+    meta_registry::add_keyword(ruby, name, draft, class)?;
+    Ok(ruby.qnil().as_value())
+}
+
+/// `JSONSchema::ContentEncoding.decode(name, value) -> String`: decodes
+/// `value` using the decoder registered under `name` (one of the built-in
+/// `base16`/`base32`/`bech32`, or a custom one added via `.register`). See
+/// [`content_encoding_registry::decode`].
+fn content_encoding_decode(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    let parsed_args = scan_args::<(String, String), (), (), (), (), ()>(args)?;
+    let (name, value) = parsed_args.required;
+    content_encoding_registry::decode(ruby, name, value)
+}
+
+/// `JSONSchema::ContentEncoding.register(name, &block)`: registers a
+/// decoder under `name`, replacing any previous one (including a built-in)
+/// registered under the same name. See
+/// [`content_encoding_registry::register`].
+fn content_encoding_register(ruby: &Ruby, args: &[Value]) -> Result<Value, Error> {
+    let parsed_args = scan_args::<(String,), (), (), (), (), ()>(args)?;
+    let (name,) = parsed_args.required;
+    let callback = ruby
+        .block_proc()
+        .map_err(|_| Error::new(ruby.exception_arg_error(), "register requires a block"))?
+        .as_value();
+    content_encoding_registry::register(name, callback)?;
+    Ok(ruby.qnil().as_value())
+}
@@ -0,0 +1,192 @@
+//! Process-lifetime registry of Ruby `format`/keyword callbacks that
+//! meta-validation (`JSONSchema::Meta.*`) applies in addition to whatever a
+//! bundled draft already understands, registered via `Meta.add_format`/
+//! `Meta.add_keyword` and optionally scoped to a single `draft:` so the same
+//! name can mean something different per dialect.
+//!
+//! Unlike `formats:`/`keywords:` passed to `Validator.new`, these entries are
+//! not tied to any one validator call: they are registered once (typically at
+//! boot, alongside custom meta-vocabulary setup) and apply to every
+//! subsequent `Meta.valid?`/`validate!`/`each_error`/`validate` call, which is
+//! why the callback values are rooted permanently with `register_address`
+//! rather than through a one-off guard.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, OnceLock},
+};
+
+use magnus::{block::Proc, gc::register_address, prelude::*, Error, Ruby, Value};
+
+use crate::{
+    options::{RubyFormatChecker, RubyKeywordFactory},
+    static_id::define_rb_intern,
+};
+
+define_rb_intern!(static SYM_NEW: "new");
+define_rb_intern!(static SYM_VALIDATE: "validate");
+define_rb_intern!(static SYM_VALID_P: "valid?");
+define_rb_intern!(static SYM_STATELESS_P: "stateless?");
+
+/// Maps a draft to the scoping key used by the registry; kept as a `&'static
+/// str` (rather than `jsonschema::Draft` itself) so entries can be stored in
+/// an ordinary `HashMap` without relying on `Draft` implementing `Hash`/`Eq`.
+fn draft_key(draft: jsonschema::Draft) -> &'static str {
+    match draft {
+        jsonschema::Draft::Draft4 => "draft4",
+        jsonschema::Draft::Draft6 => "draft6",
+        jsonschema::Draft::Draft7 => "draft7",
+        jsonschema::Draft::Draft201909 => "draft201909",
+        jsonschema::Draft::Draft202012 => "draft202012",
+        _ => "unknown",
+    }
+}
+
+type Scope = Option<&'static str>;
+
+#[derive(Default)]
+struct Formats {
+    entries: Mutex<HashMap<(Scope, String), RubyFormatChecker>>,
+}
+
+#[derive(Default)]
+struct Keywords {
+    entries: Mutex<HashMap<(Scope, String), Arc<RubyKeywordFactory>>>,
+}
+
+fn formats() -> &'static Formats {
+    static FORMATS: OnceLock<Formats> = OnceLock::new();
+    FORMATS.get_or_init(Formats::default)
+}
+
+fn keywords() -> &'static Keywords {
+    static KEYWORDS: OnceLock<Keywords> = OnceLock::new();
+    KEYWORDS.get_or_init(Keywords::default)
+}
+
+/// `JSONSchema::Meta.add_format(name, draft: nil, &block)`: registers a named
+/// `format` checker applied during meta-schema validation, the same
+/// `value, context -> bool` contract as `Validator.new(formats: {...})`.
+/// Re-registering a `(name, draft)` pair replaces the previous checker.
+pub(crate) fn add_format(
+    ruby: &Ruby,
+    name: String,
+    draft: Option<jsonschema::Draft>,
+    callback: Value,
+) -> Result<(), Error> {
+    let proc = Proc::from_value(callback).ok_or_else(|| {
+        Error::new(
+            ruby.exception_type_error(),
+            format!("Format checker for '{name}' must be a callable (Proc or Lambda)"),
+        )
+    })?;
+
+    // There is no `remove_format`, so the callback lives for the process's
+    // remaining lifetime — root it permanently rather than unregistering it
+    // when some particular call finishes.
+    register_address(&callback);
+
+    let checker = RubyFormatChecker::new(&name, proc);
+    let key = (draft.map(draft_key), name);
+    let mut entries = formats()
+        .entries
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    entries.insert(key, checker);
+    Ok(())
+}
+
+/// `JSONSchema::Meta.add_keyword(name, klass, draft: nil)`: registers a custom
+/// keyword validator class for meta-schema validation, matching the same
+/// `new(schema, value, path)` / `validate(instance, context)` contract as
+/// `Validator.new(keywords: {...})`. Scoped to `draft:` the same way as
+/// [`add_format`].
+pub(crate) fn add_keyword(
+    ruby: &Ruby,
+    name: String,
+    draft: Option<jsonschema::Draft>,
+    class: Value,
+) -> Result<(), Error> {
+    let responds_to_stateless: bool = class.funcall("respond_to?", (SYM_STATELESS_P.to_symbol(),))?;
+    let stateless = if responds_to_stateless {
+        class.funcall("stateless?", ())?
+    } else {
+        false
+    };
+
+    let (has_validate, has_valid): (bool, bool) = if stateless {
+        (
+            class.funcall("respond_to?", (SYM_VALIDATE.to_symbol(),))?,
+            class.funcall("respond_to?", (SYM_VALID_P.to_symbol(),))?,
+        )
+    } else {
+        let responds_to_new: bool = class.funcall("respond_to?", (SYM_NEW.to_symbol(),))?;
+        if !responds_to_new {
+            return Err(Error::new(
+                ruby.exception_type_error(),
+                format!("Keyword validator for '{name}' must be a class with 'new' and 'validate' methods"),
+            ));
+        }
+        (
+            class.funcall("method_defined?", (SYM_VALIDATE.to_symbol(),))?,
+            class.funcall("method_defined?", (SYM_VALID_P.to_symbol(),))?,
+        )
+    };
+    if !has_validate {
+        let requirement = if stateless {
+            "must define a class-level 'validate' method"
+        } else {
+            "must define a 'validate' instance method"
+        };
+        return Err(Error::new(
+            ruby.exception_type_error(),
+            format!("Keyword validator for '{name}' {requirement}"),
+        ));
+    }
+
+    register_address(&class);
+
+    let key = (draft.map(draft_key), name);
+    let mut entries = keywords()
+        .entries
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    entries.insert(
+        key,
+        Arc::new(RubyKeywordFactory::new(class, has_valid, stateless)),
+    );
+    Ok(())
+}
+
+/// Registered format checkers that apply to `draft` (global, `draft: nil`
+/// entries plus any scoped specifically to `draft`), for layering onto a
+/// `jsonschema::meta::options()` builder via `with_format`.
+pub(crate) fn formats_for(draft: Option<jsonschema::Draft>) -> Vec<(String, RubyFormatChecker)> {
+    let scope = draft.map(draft_key);
+    let entries = formats()
+        .entries
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    entries
+        .iter()
+        .filter(|((entry_scope, _), _)| entry_scope.is_none() || *entry_scope == scope)
+        .map(|((_, name), checker)| (name.clone(), checker.clone()))
+        .collect()
+}
+
+/// Registered keyword factories that apply to `draft`, mirroring
+/// [`formats_for`].
+pub(crate) fn keywords_for(
+    draft: Option<jsonschema::Draft>,
+) -> Vec<(String, Arc<RubyKeywordFactory>)> {
+    let scope = draft.map(draft_key);
+    let entries = keywords()
+        .entries
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    entries
+        .iter()
+        .filter(|((entry_scope, _), _)| entry_scope.is_none() || *entry_scope == scope)
+        .map(|((_, name), factory)| (name.clone(), Arc::clone(factory)))
+        .collect()
+}
@@ -1,4 +1,8 @@
 use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fs,
+    path::PathBuf,
     pin::Pin,
     sync::{Arc, Mutex},
     time::Duration,
@@ -16,32 +20,38 @@ use magnus::{
 };
 
 use crate::{
+    current_context,
     registry::Registry,
-    retriever::{make_retriever, RubyRetriever},
+    retriever::{make_retriever, AnyRetriever, NativeHttpConfig, NativeHttpRetriever},
     ser::{map_to_ruby, value_to_ruby},
     static_id::{define_rb_intern, StaticId},
     LAST_CALLBACK_ERROR,
 };
 
 // Base kwarg names
-define_rb_intern!(static KW_DRAFT: "draft");
+define_rb_intern!(pub(crate) static KW_DRAFT: "draft");
 define_rb_intern!(static KW_VALIDATE_FORMATS: "validate_formats");
 define_rb_intern!(static KW_IGNORE_UNKNOWN_FORMATS: "ignore_unknown_formats");
 define_rb_intern!(static KW_MASK: "mask");
+define_rb_intern!(static KW_VERBOSE: "verbose");
 define_rb_intern!(static KW_BASE_URI: "base_uri");
-define_rb_intern!(static KW_RETRIEVER: "retriever");
+define_rb_intern!(pub(crate) static KW_RETRIEVER: "retriever");
 define_rb_intern!(static KW_FORMATS: "formats");
 define_rb_intern!(static KW_KEYWORDS: "keywords");
 define_rb_intern!(pub(crate) static KW_REGISTRY: "registry");
+define_rb_intern!(pub(crate) static KW_IGNORE_UNKNOWN_KEYWORDS: "ignore_unknown_keywords");
+define_rb_intern!(pub(crate) static KW_CHECK_EXAMPLES: "check_examples");
 // Extra kwarg names (extracted before get_kwargs)
 define_rb_intern!(static KW_PATTERN_OPTIONS: "pattern_options");
 define_rb_intern!(static KW_EMAIL_OPTIONS: "email_options");
 define_rb_intern!(static KW_HTTP_OPTIONS: "http_options");
+define_rb_intern!(pub(crate) static KW_CONTEXT: "context");
 // EmailOptions kwargs
 define_rb_intern!(static KW_REQUIRE_TLD: "require_tld");
 define_rb_intern!(static KW_ALLOW_DOMAIN_LITERAL: "allow_domain_literal");
 define_rb_intern!(static KW_ALLOW_DISPLAY_TEXT: "allow_display_text");
 define_rb_intern!(static KW_MINIMUM_SUB_DOMAINS: "minimum_sub_domains");
+define_rb_intern!(static KW_CHECK_MX: "check_mx");
 // RegexOptions / FancyRegexOptions kwargs
 define_rb_intern!(static KW_SIZE_LIMIT: "size_limit");
 define_rb_intern!(static KW_DFA_SIZE_LIMIT: "dfa_size_limit");
@@ -51,15 +61,31 @@ define_rb_intern!(static KW_TIMEOUT: "timeout");
 define_rb_intern!(static KW_CONNECT_TIMEOUT: "connect_timeout");
 define_rb_intern!(static KW_TLS_VERIFY: "tls_verify");
 define_rb_intern!(static KW_CA_CERT: "ca_cert");
+define_rb_intern!(static KW_HEADERS: "headers");
+define_rb_intern!(static KW_PROXY: "proxy");
+define_rb_intern!(static KW_MAX_REDIRECTS: "max_redirects");
+define_rb_intern!(static KW_CLIENT_CERT: "client_cert");
+define_rb_intern!(static KW_CLIENT_KEY: "client_key");
+define_rb_intern!(static KW_MAX_RETRIES: "max_retries");
+define_rb_intern!(static KW_RETRY_BACKOFF: "retry_backoff");
+define_rb_intern!(static KW_MAX_CONCURRENT: "max_concurrent");
+define_rb_intern!(static KW_CACHE_DIR: "cache_dir");
+define_rb_intern!(static KW_CACHE_ENABLED: "cache_enabled");
 // Method symbols for respond_to? / method_defined? checks
 define_rb_intern!(static SYM_CALL: "call");
 define_rb_intern!(static SYM_NEW: "new");
 define_rb_intern!(static SYM_VALIDATE: "validate");
+define_rb_intern!(static SYM_VALID_P: "valid?");
+define_rb_intern!(static SYM_STATELESS_P: "stateless?");
 
 pub struct ParsedOptions {
     pub mask: Option<String>,
+    pub verbose: bool,
+    /// Forwarded as an extra argument into every Ruby format/custom-keyword callback
+    /// invoked during the validation call this was parsed for. See [`ContextGuard`](crate::ContextGuard).
+    pub context: Option<Value>,
     pub options: jsonschema::ValidationOptions,
-    pub retriever: Option<RubyRetriever>,
+    pub retriever: Option<AnyRetriever>,
     // Runtime callbacks invoked during `validator.*` calls (formats / custom keywords).
     // Retriever callbacks are used at build time and do not affect GVL behavior at runtime.
     pub has_ruby_callbacks: bool,
@@ -99,7 +125,7 @@ pub struct CompilationRoots {
 }
 
 impl CompilationRoots {
-    fn add(&self, value: Opaque<Value>) -> Result<(), ()> {
+    pub(crate) fn add(&self, value: Opaque<Value>) -> Result<(), ()> {
         let mut roots = self.roots.lock().map_err(|_| ())?;
         let pinned = Box::pin(value);
         register_address(pinned.as_ref().get_ref());
@@ -120,12 +146,13 @@ impl Drop for CompilationRoots {
     }
 }
 
-fn base_option_ids() -> [StaticId; 9] {
+fn base_option_ids() -> [StaticId; 10] {
     [
         *KW_DRAFT,
         *KW_VALIDATE_FORMATS,
         *KW_IGNORE_UNKNOWN_FORMATS,
         *KW_MASK,
+        *KW_VERBOSE,
         *KW_BASE_URI,
         *KW_RETRIEVER,
         *KW_FORMATS,
@@ -151,6 +178,7 @@ type BaseKwargs = (
     Option<bool>,
     Option<bool>,
     Option<String>,
+    Option<bool>,
     Option<String>,
     Option<Value>,
     Option<RHash>,
@@ -171,6 +199,7 @@ type BaseKwargsNoDraft = (
     Option<bool>,
     Option<bool>,
     Option<String>,
+    Option<bool>,
     Option<String>,
     Option<Value>,
     Option<RHash>,
@@ -178,11 +207,12 @@ type BaseKwargsNoDraft = (
     Option<Value>,
 );
 
-fn base_option_ids_no_draft() -> [StaticId; 8] {
+fn base_option_ids_no_draft() -> [StaticId; 9] {
     [
         *KW_VALIDATE_FORMATS,
         *KW_IGNORE_UNKNOWN_FORMATS,
         *KW_MASK,
+        *KW_VERBOSE,
         *KW_BASE_URI,
         *KW_RETRIEVER,
         *KW_FORMATS,
@@ -224,12 +254,22 @@ pub struct ExtractedKwargs {
     pub pattern_options: Option<Value>,
     pub email_options: Option<Value>,
     pub http_options: Option<Value>,
+    pub context: Option<Value>,
+}
+
+/// Extracts the `context:` kwarg accepted by the `Validator` instance methods
+/// (`valid?`, `validate!`, `each_error`, `evaluate`), which don't go through
+/// [`ExtractedKwargs`]/[`ParsedOptions`] since the validator is already built.
+pub fn extract_context(_ruby: &Ruby, kw: RHash) -> Result<Option<Value>, Error> {
+    let parsed: KwArgs<(), (Option<Value>,), ()> = get_kwargs(kw, &[], &[*KW_CONTEXT])?;
+    Ok(parsed.optional.0)
 }
 
 pub fn extract_kwargs(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwargs, Error> {
     let pattern_options = extract_and_delete(&kw, *KW_PATTERN_OPTIONS)?;
     let email_options = extract_and_delete(&kw, *KW_EMAIL_OPTIONS)?;
     let http_options = extract_and_delete(&kw, *KW_HTTP_OPTIONS)?;
+    let context = extract_and_delete(&kw, *KW_CONTEXT)?;
 
     let ids = base_option_ids();
     let base_kw: KwArgs<(), BaseKwargs, ()> = get_kwargs(kw, &[], &ids)?;
@@ -239,6 +279,7 @@ pub fn extract_kwargs(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwargs, Error>
         pattern_options,
         email_options,
         http_options,
+        context,
     })
 }
 
@@ -246,6 +287,7 @@ pub fn extract_evaluate_kwargs(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
     let pattern_options = extract_and_delete(&kw, *KW_PATTERN_OPTIONS)?;
     let email_options = extract_and_delete(&kw, *KW_EMAIL_OPTIONS)?;
     let http_options = extract_and_delete(&kw, *KW_HTTP_OPTIONS)?;
+    let context = extract_and_delete(&kw, *KW_CONTEXT)?;
 
     let ids = base_option_ids_no_mask();
     let base_kw: KwArgs<(), BaseKwargsNoMask, ()> = get_kwargs(kw, &[], &ids)?;
@@ -266,6 +308,7 @@ pub fn extract_evaluate_kwargs(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
             validate_formats,
             ignore_unknown_formats,
             None,
+            None,
             base_uri,
             retriever,
             formats,
@@ -275,6 +318,7 @@ pub fn extract_evaluate_kwargs(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
         pattern_options,
         email_options,
         http_options,
+        context,
     })
 }
 
@@ -282,6 +326,7 @@ pub fn extract_kwargs_no_draft(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
     let pattern_options = extract_and_delete(&kw, *KW_PATTERN_OPTIONS)?;
     let email_options = extract_and_delete(&kw, *KW_EMAIL_OPTIONS)?;
     let http_options = extract_and_delete(&kw, *KW_HTTP_OPTIONS)?;
+    let context = extract_and_delete(&kw, *KW_CONTEXT)?;
 
     let ids = base_option_ids_no_draft();
     let base_kw: KwArgs<(), BaseKwargsNoDraft, ()> = get_kwargs(kw, &[], &ids)?;
@@ -289,6 +334,7 @@ pub fn extract_kwargs_no_draft(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
         validate_formats,
         ignore_unknown_formats,
         mask,
+        verbose,
         base_uri,
         retriever,
         formats,
@@ -302,6 +348,7 @@ pub fn extract_kwargs_no_draft(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
             validate_formats,
             ignore_unknown_formats,
             mask,
+            verbose,
             base_uri,
             retriever,
             formats,
@@ -311,6 +358,7 @@ pub fn extract_kwargs_no_draft(_ruby: &Ruby, kw: RHash) -> Result<ExtractedKwarg
         pattern_options,
         email_options,
         http_options,
+        context,
     })
 }
 
@@ -338,18 +386,106 @@ fn timeout_duration(ruby: &Ruby, field: &str, value: f64) -> Result<Duration, Er
     })
 }
 
+fn max_redirects_count(ruby: &Ruby, value: i64) -> Result<u32, Error> {
+    non_negative_u32(ruby, "max_redirects", value)
+}
+
+fn non_negative_u32(ruby: &Ruby, field: &str, value: i64) -> Result<u32, Error> {
+    u32::try_from(value).map_err(|_| {
+        Error::new(
+            ruby.exception_arg_error(),
+            format!("http_options.{field} must not be negative"),
+        )
+    })
+}
+
+/// Converts a Ruby `HttpOptions`'s `headers`/`proxy`/`max_redirects`/
+/// `max_retries`/`retry_backoff`/`max_concurrent` into the plain config
+/// [`NativeHttpRetriever`] builds its `reqwest` client (and retry/concurrency
+/// behavior) from. `timeout`/`connect_timeout`/`tls_verify`/`ca_cert`/
+/// `client_cert`/`client_key` are left out — they only ever configured the
+/// external `jsonschema::HttpOptions` default retriever (see
+/// `make_options_from_kwargs`), and `NativeHttpRetriever` doesn't apply them
+/// today.
+pub(crate) fn native_http_config(hopts: &HttpOptions) -> NativeHttpConfig {
+    NativeHttpConfig {
+        headers: hopts.headers.clone(),
+        proxy: hopts.proxy.clone(),
+        max_redirects: hopts.max_redirects,
+        max_retries: hopts.max_retries,
+        retry_backoff: hopts.retry_backoff,
+        max_concurrent: hopts.max_concurrent,
+        cache_dir: hopts.cache_dir.clone().map(PathBuf::from),
+        cache_enabled: hopts.cache_enabled.unwrap_or(true),
+    }
+}
+
+thread_local! {
+    /// Custom failure reasons stashed by [`RubyFormatChecker::check`] when a
+    /// proc returns a `String` instead of a boolean, keyed by `(format name,
+    /// checked value)` since several format checks can fail within a single
+    /// validation run before any of their messages get rendered. Consumed
+    /// (removed) by [`take_format_failure_reason`] once `error_message`
+    /// renders the matching error, so a stale reason can't leak into an
+    /// unrelated later error for the same `(format, value)` pair.
+    static FORMAT_FAILURE_REASONS: RefCell<HashMap<(Arc<str>, String), String>> =
+        RefCell::new(HashMap::new());
+}
+
+/// The custom reason a registered `format` checker gave for rejecting
+/// `value` under `format`, if any — see the doc on
+/// [`RubyFormatChecker::check`] for how it gets there.
+pub(crate) fn take_format_failure_reason(format: &str, value: &str) -> Option<String> {
+    FORMAT_FAILURE_REASONS.with(|reasons| {
+        let mut reasons = reasons.borrow_mut();
+        let key = reasons
+            .keys()
+            .find(|(name, checked)| &**name == format && checked == value)?
+            .clone();
+        reasons.remove(&key)
+    })
+}
+
 /// Wrapper for a Ruby format checker proc that can be called from Rust.
-struct RubyFormatChecker {
+#[derive(Clone)]
+pub(crate) struct RubyFormatChecker {
     proc: Opaque<Proc>,
+    name: Arc<str>,
 }
 
 impl RubyFormatChecker {
-    fn check(&self, value: &str) -> bool {
+    pub(crate) fn new(name: &str, proc: Proc) -> Self {
+        Self {
+            proc: Opaque::from(proc),
+            name: Arc::from(name),
+        }
+    }
+
+    /// Calls the wrapped proc and interprets its return value: `true`/`false`
+    /// map to valid/invalid with the default "does not match format" message,
+    /// same as before, but a returned `String` (or an object convertible to
+    /// one, e.g. via `to_str`) is treated as an invalid result carrying a
+    /// custom reason — stashed in [`FORMAT_FAILURE_REASONS`] for
+    /// `error_message` to pick up when it renders this check's error, rather
+    /// than being silently coerced to `true` by Ruby's own truthiness rules.
+    pub(crate) fn check(&self, value: &str) -> bool {
         let ruby = Ruby::get().expect("Ruby VM should be initialized");
         let proc = ruby.get_inner(self.proc);
-        let result: Result<bool, _> = proc.call((value,));
+        let context = current_context(&ruby);
+        let result: Result<Value, _> = proc.call((value, context));
         match result {
-            Ok(v) => v,
+            Ok(v) => {
+                if let Ok(reason) = String::try_convert(v) {
+                    FORMAT_FAILURE_REASONS.with(|reasons| {
+                        reasons
+                            .borrow_mut()
+                            .insert((Arc::clone(&self.name), value.to_string()), reason);
+                    });
+                    false
+                } else {
+                    bool::try_convert(v).unwrap_or(false)
+                }
+            }
             Err(e) => {
                 LAST_CALLBACK_ERROR.with(|last| {
                     *last.borrow_mut() = Some(e);
@@ -361,13 +497,53 @@ impl RubyFormatChecker {
 }
 
 /// Wrapper for a Ruby custom keyword validator factory.
-struct RubyKeywordFactory {
-    class: Opaque<Value>,
+#[derive(Clone, Copy)]
+pub(crate) struct RubyKeywordFactory {
+    pub(crate) class: Opaque<Value>,
+    /// Whether the class defines a `valid?` method, checked once at
+    /// registration (alongside the mandatory `validate` check) so
+    /// `RubyKeyword::is_valid` knows without a `respond_to?` call on every
+    /// instance whether it can take the cheaper `valid?`-only path.
+    pub(crate) has_valid: bool,
+    /// Whether the class declared itself stateless (via a class-level
+    /// `stateless?`), meaning `validate`/`valid?` are defined as
+    /// class/singleton methods rather than instance methods. A stateless
+    /// class is never `new`'d: at each occurrence the `with_keyword` factory
+    /// closure boxes the class itself as the `RubyKeyword` instance, instead
+    /// of instantiating (and GC-rooting) a fresh object per occurrence.
+    pub(crate) stateless: bool,
+}
+
+impl RubyKeywordFactory {
+    pub(crate) fn new(class: Value, has_valid: bool, stateless: bool) -> Self {
+        Self {
+            class: Opaque::from(class),
+            has_valid,
+            stateless,
+        }
+    }
 }
 
 /// Wrapper for a Ruby custom keyword validator instance.
 struct RubyKeyword {
     instance: Opaque<Value>,
+    has_valid: bool,
+}
+
+/// Boxes a Ruby keyword validator instance (already `new`'d from a registered
+/// factory class, or the class itself when the factory is stateless) as a
+/// `jsonschema::Keyword`, for callers outside this module
+/// that install [`RubyKeywordFactory`]-backed keywords (e.g. meta-validation's
+/// `Meta.add_keyword`). `has_valid` comes from the owning
+/// [`RubyKeywordFactory`] — see its doc for why it's checked once up front.
+pub(crate) fn boxed_ruby_keyword(
+    instance: Opaque<Value>,
+    has_valid: bool,
+) -> Box<dyn jsonschema::Keyword> {
+    Box::new(RubyKeyword {
+        instance,
+        has_valid,
+    })
 }
 
 impl jsonschema::Keyword for RubyKeyword {
@@ -381,21 +557,33 @@ impl jsonschema::Keyword for RubyKeyword {
         })?;
 
         let keyword = ruby.get_inner(self.instance);
-        let result: Result<Value, _> = keyword.funcall("validate", (rb_instance,));
+        let context = current_context(&ruby);
+        let result: Result<Value, _> = keyword.funcall("validate", (rb_instance, context));
         match result {
             Ok(_) => Ok(()),
             Err(e) => Err(jsonschema::ValidationError::custom(e.to_string())),
         }
     }
 
+    /// Calls `valid?` instead of `validate` when the class defines one,
+    /// skipping the error-object allocation and the full `validate` path for
+    /// callers (`valid?`-style top-level APIs) that only need a yes/no
+    /// answer — the same `validate`/`is_valid` split the Rust
+    /// `jsonschema::Keyword` trait itself draws.
     fn is_valid(&self, instance: &serde_json::Value) -> bool {
         let ruby = Ruby::get().expect("Ruby VM should be initialized");
         let Ok(rb_instance) = value_to_ruby(&ruby, instance) else {
             return false;
         };
         let inst = ruby.get_inner(self.instance);
-        let result: Result<Value, _> = inst.funcall("validate", (rb_instance,));
-        result.is_ok()
+        let context = current_context(&ruby);
+        if self.has_valid {
+            let result: Result<bool, _> = inst.funcall("valid?", (rb_instance, context));
+            result.unwrap_or(false)
+        } else {
+            let result: Result<Value, _> = inst.funcall("validate", (rb_instance, context));
+            result.is_ok()
+        }
     }
 }
 
@@ -406,6 +594,7 @@ pub fn make_options_from_kwargs(
     validate_formats: Option<bool>,
     ignore_unknown_formats: Option<bool>,
     mask: Option<String>,
+    verbose: Option<bool>,
     base_uri: Option<String>,
     retriever_val: Option<Value>,
     formats: Option<RHash>,
@@ -491,6 +680,11 @@ pub fn make_options_from_kwargs(
                         }
                         retriever = Some(ret);
                     }
+                } else if let Some(cache_dir) = reg.native_cache_dir() {
+                    // Holds no Ruby values, so it needs no callback/compilation rooting.
+                    let native = NativeHttpRetriever::new(cache_dir.clone(), reg.native_http_config())
+                        .map_err(|e| Error::new(ruby.exception_arg_error(), e.to_string()))?;
+                    retriever = Some(AnyRetriever::Native(native));
                 }
             }
         }
@@ -539,9 +733,7 @@ pub fn make_options_from_kwargs(
                 roots.push(Opaque::from(callback));
             }
 
-            let checker = RubyFormatChecker {
-                proc: Opaque::from(proc),
-            };
+            let checker = RubyFormatChecker::new(&name, proc);
 
             opts = opts.with_format(name, move |value: &str| checker.check(value));
         }
@@ -554,30 +746,48 @@ pub fn make_options_from_kwargs(
             let name: String = pair.entry(0)?;
             let callback: Value = pair.entry(1)?;
 
-            let responds_to_new: bool = callback.funcall("respond_to?", (SYM_NEW.to_symbol(),))?;
-            if !responds_to_new {
-                return Err(Error::new(
-                    ruby.exception_type_error(),
-                    format!(
-                        "Keyword validator for '{name}' must be a class with 'new' and 'validate' methods"
-                    ),
-                ));
-            }
+            let responds_to_stateless: bool =
+                callback.funcall("respond_to?", (SYM_STATELESS_P.to_symbol(),))?;
+            let stateless = if responds_to_stateless {
+                callback.funcall("stateless?", ())?
+            } else {
+                false
+            };
 
-            let has_validate: bool =
-                callback.funcall("method_defined?", (SYM_VALIDATE.to_symbol(),))?;
+            let (has_validate, has_valid): (bool, bool) = if stateless {
+                (
+                    callback.funcall("respond_to?", (SYM_VALIDATE.to_symbol(),))?,
+                    callback.funcall("respond_to?", (SYM_VALID_P.to_symbol(),))?,
+                )
+            } else {
+                let responds_to_new: bool =
+                    callback.funcall("respond_to?", (SYM_NEW.to_symbol(),))?;
+                if !responds_to_new {
+                    return Err(Error::new(
+                        ruby.exception_type_error(),
+                        format!(
+                            "Keyword validator for '{name}' must be a class with 'new' and 'validate' methods"
+                        ),
+                    ));
+                }
+                (
+                    callback.funcall("method_defined?", (SYM_VALIDATE.to_symbol(),))?,
+                    callback.funcall("method_defined?", (SYM_VALID_P.to_symbol(),))?,
+                )
+            };
             if !has_validate {
+                let requirement = if stateless {
+                    "must define a class-level 'validate' method"
+                } else {
+                    "must define a 'validate' instance method"
+                };
                 return Err(Error::new(
                     ruby.exception_type_error(),
-                    format!(
-                        "Keyword validator for '{name}' must define a 'validate' instance method"
-                    ),
+                    format!("Keyword validator for '{name}' {requirement}"),
                 ));
             }
 
-            let callback_wrapper = Arc::new(RubyKeywordFactory {
-                class: Opaque::from(callback),
-            });
+            let callback_wrapper = Arc::new(RubyKeywordFactory::new(callback, has_valid, stateless));
             compilation_roots
                 .add(Opaque::from(callback))
                 .map_err(|()| {
@@ -608,6 +818,13 @@ pub fn make_options_from_kwargs(
                     let name_err = name_for_error.clone();
                     let factory = callback_wrapper.clone();
 
+                    if factory.stateless {
+                        // No per-occurrence state, so skip `new`-ing (and GC-rooting)
+                        // an instance altogether: the class itself, already rooted
+                        // once above, stands in as the `RubyKeyword` instance.
+                        return Ok(boxed_ruby_keyword(factory.class, factory.has_valid));
+                    }
+
                     // Convert parent schema map to Ruby hash directly
                     let rb_schema = map_to_ruby(&inner_ruby, parent).map_err(|e| {
                         jsonschema::ValidationError::custom(format!(
@@ -656,6 +873,7 @@ pub fn make_options_from_kwargs(
                             roots.push(opaque_inst);
                             Ok(Box::new(RubyKeyword {
                                 instance: opaque_inst,
+                                has_valid: factory.has_valid,
                             })
                                 as Box<dyn jsonschema::Keyword>)
                         }
@@ -723,6 +941,32 @@ pub fn make_options_from_kwargs(
             email_opts = email_opts.with_minimum_sub_domains(min);
         }
         opts = opts.with_email_options(email_opts);
+
+        if eopts.check_mx {
+            // The external crate has no hook to layer a deliverability check
+            // onto its built-in "email" format checker, so this replaces it
+            // outright with one that re-implements the same syntax rules and
+            // additionally requires a resolvable mail exchanger — see
+            // `email_mx` for the rationale, its RFC 5321 implicit-MX
+            // fallback, and why it needs to know whether the GVL is already
+            // held at the point it runs.
+            let eopts_for_check = EmailOptions {
+                require_tld: eopts.require_tld,
+                allow_domain_literal: eopts.allow_domain_literal,
+                allow_display_text: eopts.allow_display_text,
+                minimum_sub_domains: eopts.minimum_sub_domains,
+                check_mx: eopts.check_mx,
+            };
+            // `has_ruby_callbacks` is already final by this point (the
+            // `formats`/`keywords` loops above are the only other things
+            // that set it), and it's exactly the flag that decides whether
+            // the enclosing `validate`/`is_valid` call holds the GVL for its
+            // whole duration instead of releasing it up front.
+            let gvl_held_during_validation = has_ruby_callbacks;
+            opts = opts.with_format("email", move |value: &str| {
+                crate::email_mx::check(value, &eopts_for_check, gvl_held_during_validation)
+            });
+        }
     }
 
     if let Some(val) = http_options_val {
@@ -749,6 +993,24 @@ pub fn make_options_from_kwargs(
         if let Some(ref ca_cert) = hopts.ca_cert {
             http_opts = http_opts.add_root_certificate(ca_cert);
         }
+        match (&hopts.client_cert, &hopts.client_key) {
+            (Some(cert), Some(key)) => {
+                let cert_pem = load_pem_or_path(cert)
+                    .map_err(|e| Error::new(ruby.exception_arg_error(), format!("failed to read http_options client_cert: {e}")))?;
+                let key_pem = load_pem_or_path(key)
+                    .map_err(|e| Error::new(ruby.exception_arg_error(), format!("failed to read http_options client_key: {e}")))?;
+                http_opts = http_opts.add_client_certificate(&cert_pem, &key_pem).map_err(|e| {
+                    Error::new(ruby.exception_arg_error(), e.to_string())
+                })?;
+            }
+            (None, None) => {}
+            _ => {
+                return Err(Error::new(
+                    ruby.exception_arg_error(),
+                    "http_options.client_cert and client_key must both be provided together",
+                ));
+            }
+        }
         opts = opts
             .with_http_options(&http_opts)
             .map_err(|e| Error::new(ruby.exception_arg_error(), e.to_string()))?;
@@ -756,6 +1018,8 @@ pub fn make_options_from_kwargs(
 
     Ok(ParsedOptions {
         mask,
+        verbose: verbose.unwrap_or(false),
+        context: None, // set by `build_parsed_options` from `ExtractedKwargs::context`
         options: opts,
         retriever,
         has_ruby_callbacks,
@@ -770,6 +1034,7 @@ pub struct EmailOptions {
     pub allow_domain_literal: bool,
     pub allow_display_text: bool,
     pub minimum_sub_domains: Option<usize>,
+    pub check_mx: bool,
 }
 
 impl EmailOptions {
@@ -781,16 +1046,27 @@ impl EmailOptions {
             *KW_ALLOW_DOMAIN_LITERAL,
             *KW_ALLOW_DISPLAY_TEXT,
             *KW_MINIMUM_SUB_DOMAINS,
+            *KW_CHECK_MX,
         ];
-        let kw: KwArgs<(), (Option<bool>, Option<bool>, Option<bool>, Option<usize>), ()> =
-            get_kwargs(parsed.keywords, &[], &ids)?;
-        let (require_tld, allow_domain_literal, allow_display_text, minimum_sub_domains) =
+        let kw: KwArgs<
+            (),
+            (
+                Option<bool>,
+                Option<bool>,
+                Option<bool>,
+                Option<usize>,
+                Option<bool>,
+            ),
+            (),
+        > = get_kwargs(parsed.keywords, &[], &ids)?;
+        let (require_tld, allow_domain_literal, allow_display_text, minimum_sub_domains, check_mx) =
             kw.optional;
         Ok(EmailOptions {
             require_tld: require_tld.unwrap_or(false),
             allow_domain_literal: allow_domain_literal.unwrap_or(true),
             allow_display_text: allow_display_text.unwrap_or(true),
             minimum_sub_domains,
+            check_mx: check_mx.unwrap_or(false),
         })
     }
 
@@ -810,6 +1086,10 @@ impl EmailOptions {
         self.minimum_sub_domains
     }
 
+    fn check_mx(&self) -> bool {
+        self.check_mx
+    }
+
     fn inspect(&self) -> String {
         use std::fmt::Write;
         let mut s = String::from("#<JSONSchema::EmailOptions require_tld=");
@@ -831,6 +1111,8 @@ impl EmailOptions {
             Some(n) => write!(s, "{n}").expect("Failed to write minimum_sub_domains"),
             None => s.push_str("nil"),
         }
+        s.push_str(", check_mx=");
+        s.push_str(if self.check_mx { "true" } else { "false" });
         s.push('>');
         s
     }
@@ -942,26 +1224,140 @@ pub struct HttpOptions {
     pub connect_timeout: Option<f64>,
     pub tls_verify: bool,
     pub ca_cert: Option<String>,
+    pub headers: HashMap<String, String>,
+    pub proxy: Option<String>,
+    pub max_redirects: Option<u32>,
+    pub client_cert: Option<String>,
+    pub client_key: Option<String>,
+    pub max_retries: Option<u32>,
+    pub retry_backoff: Option<f64>,
+    pub max_concurrent: Option<u32>,
+    pub cache_dir: Option<String>,
+    pub cache_enabled: Option<bool>,
+}
+
+/// Header names that conventionally carry credentials, whose values
+/// [`redact_header_value`] hides in `HttpOptions#inspect`.
+const CREDENTIAL_HEADER_NAMES: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+    "api-key",
+];
+
+fn redact_header_value<'a>(name: &str, value: &'a str) -> std::borrow::Cow<'a, str> {
+    if CREDENTIAL_HEADER_NAMES.contains(&name.to_ascii_lowercase().as_str()) {
+        std::borrow::Cow::Borrowed("<redacted>")
+    } else {
+        std::borrow::Cow::Borrowed(value)
+    }
+}
+
+/// Resolves a `client_cert`/`client_key` value to PEM content: if `value`
+/// names an existing file it is read from disk, otherwise `value` is assumed
+/// to already be inline PEM text.
+fn load_pem_or_path(value: &str) -> Result<String, std::io::Error> {
+    if std::path::Path::new(value).is_file() {
+        fs::read_to_string(value)
+    } else {
+        Ok(value.to_string())
+    }
 }
 
 impl HttpOptions {
     #[allow(clippy::type_complexity)]
-    fn new_impl(args: &[Value]) -> Result<Self, Error> {
+    fn new_impl(ruby: &Ruby, args: &[Value]) -> Result<Self, Error> {
         let parsed = scan_args::<(), (), (), (), _, ()>(args)?;
         let ids = [
             *KW_TIMEOUT,
             *KW_CONNECT_TIMEOUT,
             *KW_TLS_VERIFY,
             *KW_CA_CERT,
+            *KW_HEADERS,
+            *KW_PROXY,
+            *KW_MAX_REDIRECTS,
+            *KW_CLIENT_CERT,
+            *KW_CLIENT_KEY,
+            *KW_MAX_RETRIES,
+            *KW_RETRY_BACKOFF,
+            *KW_MAX_CONCURRENT,
+            *KW_CACHE_DIR,
+            *KW_CACHE_ENABLED,
         ];
-        let kw: KwArgs<(), (Option<f64>, Option<f64>, Option<bool>, Option<String>), ()> =
-            get_kwargs(parsed.keywords, &[], &ids)?;
-        let (timeout, connect_timeout, tls_verify, ca_cert) = kw.optional;
+        #[allow(clippy::type_complexity)]
+        let kw: KwArgs<
+            (),
+            (
+                Option<f64>,
+                Option<f64>,
+                Option<bool>,
+                Option<String>,
+                Option<RHash>,
+                Option<String>,
+                Option<i64>,
+                Option<String>,
+                Option<String>,
+                Option<i64>,
+                Option<f64>,
+                Option<i64>,
+                Option<String>,
+                Option<bool>,
+            ),
+            (),
+        > = get_kwargs(parsed.keywords, &[], &ids)?;
+        let (
+            timeout,
+            connect_timeout,
+            tls_verify,
+            ca_cert,
+            headers_hash,
+            proxy,
+            max_redirects,
+            client_cert,
+            client_key,
+            max_retries,
+            retry_backoff,
+            max_concurrent,
+            cache_dir,
+            cache_enabled,
+        ) = kw.optional;
+
+        let mut headers = HashMap::new();
+        if let Some(headers_hash) = headers_hash {
+            for item in headers_hash.enumeratorize("each", ()) {
+                let pair: magnus::RArray = magnus::TryConvert::try_convert(item?)?;
+                let name: String = pair.entry(0)?;
+                let value: String = pair.entry(1)?;
+                headers.insert(name, value);
+            }
+        }
+        let max_redirects = max_redirects
+            .map(|value| max_redirects_count(ruby, value))
+            .transpose()?;
+        let max_retries = max_retries
+            .map(|value| non_negative_u32(ruby, "max_retries", value))
+            .transpose()?;
+        let max_concurrent = max_concurrent
+            .map(|value| non_negative_u32(ruby, "max_concurrent", value))
+            .transpose()?;
+
         Ok(HttpOptions {
             timeout,
             connect_timeout,
             tls_verify: tls_verify.unwrap_or(true),
             ca_cert,
+            headers,
+            proxy,
+            max_redirects,
+            client_cert,
+            client_key,
+            max_retries,
+            retry_backoff,
+            max_concurrent,
+            cache_dir,
+            cache_enabled,
         })
     }
 
@@ -981,6 +1377,50 @@ impl HttpOptions {
         self.ca_cert.clone()
     }
 
+    fn headers(&self, ruby: &Ruby) -> Result<Value, Error> {
+        let hash = ruby.hash_new_capa(self.headers.len());
+        for (name, value) in &self.headers {
+            hash.aset(name.as_str(), value.as_str())?;
+        }
+        Ok(hash.as_value())
+    }
+
+    fn proxy(&self) -> Option<String> {
+        self.proxy.clone()
+    }
+
+    fn max_redirects(&self) -> Option<u32> {
+        self.max_redirects
+    }
+
+    fn client_cert(&self) -> Option<String> {
+        self.client_cert.clone()
+    }
+
+    fn client_key(&self) -> Option<String> {
+        self.client_key.clone()
+    }
+
+    fn max_retries(&self) -> Option<u32> {
+        self.max_retries
+    }
+
+    fn retry_backoff(&self) -> Option<f64> {
+        self.retry_backoff
+    }
+
+    fn max_concurrent(&self) -> Option<u32> {
+        self.max_concurrent
+    }
+
+    fn cache_dir(&self) -> Option<String> {
+        self.cache_dir.clone()
+    }
+
+    fn cache_enabled(&self) -> bool {
+        self.cache_enabled.unwrap_or(true)
+    }
+
     fn inspect(&self) -> String {
         use std::fmt::Write;
         let mut s = String::from("#<JSONSchema::HttpOptions timeout=");
@@ -1000,6 +1440,67 @@ impl HttpOptions {
             Some(c) => write!(s, "\"{c}\"").expect("Failed to write ca_cert"),
             None => s.push_str("nil"),
         }
+        s.push_str(", headers={");
+        let mut names: Vec<&String> = self.headers.keys().collect();
+        names.sort();
+        for (i, name) in names.iter().enumerate() {
+            if i > 0 {
+                s.push_str(", ");
+            }
+            let value = &self.headers[*name];
+            write!(s, "{name}={}", redact_header_value(name, value))
+                .expect("Failed to write headers");
+        }
+        s.push_str("}, proxy=");
+        match &self.proxy {
+            Some(p) => write!(s, "\"{p}\"").expect("Failed to write proxy"),
+            None => s.push_str("nil"),
+        }
+        s.push_str(", max_redirects=");
+        match self.max_redirects {
+            Some(r) => write!(s, "{r}").expect("Failed to write max_redirects"),
+            None => s.push_str("nil"),
+        }
+        // `client_cert` is usually public, but `client_key` is private key
+        // material, so neither is printed in full here.
+        s.push_str(", client_cert=");
+        s.push_str(if self.client_cert.is_some() {
+            "<set>"
+        } else {
+            "nil"
+        });
+        s.push_str(", client_key=");
+        s.push_str(if self.client_key.is_some() {
+            "<redacted>"
+        } else {
+            "nil"
+        });
+        s.push_str(", max_retries=");
+        match self.max_retries {
+            Some(r) => write!(s, "{r}").expect("Failed to write max_retries"),
+            None => s.push_str("nil"),
+        }
+        s.push_str(", retry_backoff=");
+        match self.retry_backoff {
+            Some(b) => write!(s, "{b}").expect("Failed to write retry_backoff"),
+            None => s.push_str("nil"),
+        }
+        s.push_str(", max_concurrent=");
+        match self.max_concurrent {
+            Some(c) => write!(s, "{c}").expect("Failed to write max_concurrent"),
+            None => s.push_str("nil"),
+        }
+        s.push_str(", cache_dir=");
+        match &self.cache_dir {
+            Some(c) => write!(s, "\"{c}\"").expect("Failed to write cache_dir"),
+            None => s.push_str("nil"),
+        }
+        s.push_str(", cache_enabled=");
+        s.push_str(if self.cache_enabled.unwrap_or(true) {
+            "true"
+        } else {
+            "false"
+        });
         s.push('>');
         s
     }
@@ -1021,6 +1522,7 @@ pub fn define_classes(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
         "minimum_sub_domains",
         method!(EmailOptions::minimum_sub_domains, 0),
     )?;
+    email_class.define_method("check_mx", method!(EmailOptions::check_mx, 0))?;
     email_class.define_method("inspect", method!(EmailOptions::inspect, 0))?;
 
     let regex_class = module.define_class("RegexOptions", ruby.class_object())?;
@@ -1048,6 +1550,16 @@ pub fn define_classes(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
     http_class.define_method("connect_timeout", method!(HttpOptions::connect_timeout, 0))?;
     http_class.define_method("tls_verify", method!(HttpOptions::tls_verify, 0))?;
     http_class.define_method("ca_cert", method!(HttpOptions::ca_cert, 0))?;
+    http_class.define_method("headers", method!(HttpOptions::headers, 0))?;
+    http_class.define_method("proxy", method!(HttpOptions::proxy, 0))?;
+    http_class.define_method("max_redirects", method!(HttpOptions::max_redirects, 0))?;
+    http_class.define_method("client_cert", method!(HttpOptions::client_cert, 0))?;
+    http_class.define_method("client_key", method!(HttpOptions::client_key, 0))?;
+    http_class.define_method("max_retries", method!(HttpOptions::max_retries, 0))?;
+    http_class.define_method("retry_backoff", method!(HttpOptions::retry_backoff, 0))?;
+    http_class.define_method("max_concurrent", method!(HttpOptions::max_concurrent, 0))?;
+    http_class.define_method("cache_dir", method!(HttpOptions::cache_dir, 0))?;
+    http_class.define_method("cache_enabled", method!(HttpOptions::cache_enabled, 0))?;
     http_class.define_method("inspect", method!(HttpOptions::inspect, 0))?;
 
     Ok(())
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use magnus::{
     function,
     gc::{register_address, unregister_address},
@@ -8,7 +10,11 @@ use magnus::{
     DataTypeFunctions, Error, RArray, RModule, Ruby, TryConvert, Value,
 };
 
-use crate::{options::parse_draft_symbol, retriever::make_retriever, ser::to_value};
+use crate::{
+    options::{native_http_config, parse_draft_symbol, HttpOptions},
+    retriever::{make_registry_retriever, AnyRetriever, NativeHttpConfig},
+    ser::to_value,
+};
 
 struct RetrieverBuildRootGuard {
     // Keep roots in a heap allocation so addresses passed to Ruby GC are stable
@@ -42,6 +48,13 @@ impl Drop for RetrieverBuildRootGuard {
 pub struct Registry {
     pub inner: jsonschema::Registry,
     retriever_root: Option<Opaque<Value>>,
+    // Set when `retriever: :http` installs the native retriever, which holds no
+    // Ruby values and therefore needs no entry in `retriever_root`/GC marking.
+    native_cache_dir: Option<PathBuf>,
+    // Headers/proxy/redirect-limit the native retriever was built with, kept so a
+    // `Validator.new(registry: ...)` that rebuilds its own `NativeHttpRetriever`
+    // (see `make_options_from_kwargs`) matches what `Registry.new` configured.
+    native_http_config: NativeHttpConfig,
 }
 
 impl DataTypeFunctions for Registry {
@@ -58,6 +71,8 @@ impl TryConvert for Registry {
         Ok(Registry {
             inner: typed.inner.clone(),
             retriever_root: typed.retriever_root,
+            native_cache_dir: typed.native_cache_dir.clone(),
+            native_http_config: typed.native_http_config.clone(),
         })
     }
 }
@@ -67,14 +82,43 @@ impl Registry {
         let parsed_args = scan_args::<(RArray,), (), (), (), _, ()>(args)?;
         let (resources,) = parsed_args.required;
         #[allow(clippy::type_complexity)]
-        let kw: magnus::scan_args::KwArgs<(), (Option<Option<Value>>, Option<Value>), ()> =
-            get_kwargs(parsed_args.keywords, &[], &["draft", "retriever"])?;
+        let kw: magnus::scan_args::KwArgs<
+            (),
+            (
+                Option<Option<Value>>,
+                Option<Value>,
+                Option<String>,
+                Option<Value>,
+            ),
+            (),
+        > = get_kwargs(
+            parsed_args.keywords,
+            &[],
+            &["draft", "retriever", "cache_dir", "http_options"],
+        )?;
         let draft_val = kw.optional.0.flatten();
         let retriever_val = kw.optional.1;
+        let cache_dir_val = kw.optional.2.map(PathBuf::from);
+        let http_options_val = kw.optional.3;
+
+        let http_config = match http_options_val {
+            Some(val) if !val.is_nil() => {
+                let hopts: &HttpOptions = TryConvert::try_convert(val).map_err(|_| {
+                    Error::new(
+                        ruby.exception_type_error(),
+                        "http_options must be an HttpOptions instance",
+                    )
+                })?;
+                native_http_config(hopts)
+            }
+            _ => NativeHttpConfig::default(),
+        };
 
         let mut builder = jsonschema::Registry::options();
         let mut retriever_root = None;
         let mut retriever_build_root = None;
+        let mut native_cache_dir = None;
+        let mut native_http_config_out = NativeHttpConfig::default();
 
         if let Some(val) = draft_val {
             let draft = parse_draft_symbol(ruby, val)?;
@@ -82,10 +126,15 @@ impl Registry {
         }
 
         if let Some(val) = retriever_val {
-            if let Some(ret) = make_retriever(ruby, val)? {
+            if let Some(ret) = make_registry_retriever(ruby, val, cache_dir_val, &http_config)? {
+                if let AnyRetriever::Ruby(_) = &ret {
+                    retriever_root = Some(Opaque::from(val));
+                    retriever_build_root = Some(val);
+                } else if let AnyRetriever::Native(native) = &ret {
+                    native_cache_dir = Some(native.cache_dir().to_path_buf());
+                    native_http_config_out = http_config.clone();
+                }
                 builder = builder.retriever(ret);
-                retriever_root = Some(Opaque::from(val));
-                retriever_build_root = Some(val);
             }
         }
 
@@ -117,6 +166,8 @@ impl Registry {
         Ok(Registry {
             inner: registry,
             retriever_root,
+            native_cache_dir,
+            native_http_config: native_http_config_out,
         })
     }
 
@@ -127,6 +178,14 @@ impl Registry {
     pub(crate) fn retriever_value(&self, ruby: &Ruby) -> Option<Value> {
         self.retriever_root.map(|root| ruby.get_inner(root))
     }
+
+    pub(crate) fn native_cache_dir(&self) -> Option<&PathBuf> {
+        self.native_cache_dir.as_ref()
+    }
+
+    pub(crate) fn native_http_config(&self) -> &NativeHttpConfig {
+        &self.native_http_config
+    }
 }
 
 pub fn define_class(ruby: &Ruby, module: &RModule) -> Result<(), Error> {
@@ -1,6 +1,14 @@
 //! Retriever callback wrapper for Ruby.
+use std::{
+    collections::HashMap,
+    fs,
+    path::PathBuf,
+    sync::{Condvar, Mutex},
+    time::Duration,
+};
+
 use jsonschema::{Retrieve, Uri};
-use magnus::{block::Proc, prelude::*, value::Opaque, Error, Ruby, Value};
+use magnus::{block::Proc, prelude::*, value::Opaque, Error, Ruby, Symbol, Value};
 use serde_json::Value as JsonValue;
 
 use crate::ser::to_value;
@@ -84,7 +92,7 @@ impl Retrieve for RubyRetriever {
 }
 
 /// Convert a Ruby value (should be a Proc) to a retriever, if present
-pub fn make_retriever(ruby: &Ruby, value: Value) -> Result<Option<RubyRetriever>, Error> {
+pub fn make_retriever(ruby: &Ruby, value: Value) -> Result<Option<AnyRetriever>, Error> {
     if value.is_nil() {
         return Ok(None);
     }
@@ -96,5 +104,564 @@ pub fn make_retriever(ruby: &Ruby, value: Value) -> Result<Option<RubyRetriever>
         )
     })?;
 
-    Ok(Some(RubyRetriever::new(proc)))
+    Ok(Some(AnyRetriever::Ruby(RubyRetriever::new(proc))))
+}
+
+/// Whether `value` is the `:http` symbol requesting the built-in native retriever,
+/// as opposed to a user-supplied Proc.
+pub fn is_native_http_request(value: Value) -> bool {
+    Symbol::from_value(value)
+        .and_then(|sym| sym.name().ok().map(|name| name.as_ref() == "http"))
+        .unwrap_or(false)
+}
+
+/// Convert a Ruby value (a Proc, or the `:http` symbol) to a retriever, if present.
+///
+/// `:http` installs [`NativeHttpRetriever`], caching fetched documents under
+/// `cache_dir` (or [`default_cache_dir`] when not given) and applying `http_config`
+/// (headers, proxy, redirect limit — see [`NativeHttpConfig`]) to every fetch, so
+/// the common case of resolving remote `$ref`s over HTTP(S) needs no Ruby callback.
+pub fn make_registry_retriever(
+    ruby: &Ruby,
+    value: Value,
+    cache_dir: Option<PathBuf>,
+    http_config: &NativeHttpConfig,
+) -> Result<Option<AnyRetriever>, Error> {
+    if value.is_nil() {
+        return Ok(None);
+    }
+
+    if is_native_http_request(value) {
+        let cache_dir = cache_dir
+            .or_else(|| http_config.cache_dir.clone())
+            .unwrap_or_else(default_cache_dir);
+        let retriever = NativeHttpRetriever::new(cache_dir, http_config)
+            .map_err(|e| Error::new(ruby.exception_arg_error(), e.to_string()))?;
+        return Ok(Some(AnyRetriever::Native(retriever)));
+    }
+
+    make_retriever(ruby, value)
+}
+
+#[derive(Debug)]
+pub enum NativeRetrieverError {
+    Fetch { uri: String, message: String },
+    Parse { uri: String, source: serde_json::Error },
+    UnsupportedScheme(String),
+    InvalidHeader { name: String, message: String },
+    InvalidProxy(String),
+    ClientBuild(String),
+}
+
+impl std::fmt::Display for NativeRetrieverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fetch { uri, message } => write!(f, "failed to fetch {uri}: {message}"),
+            Self::Parse { uri, source } => write!(f, "failed to parse {uri} as JSON: {source}"),
+            Self::UnsupportedScheme(scheme) => write!(f, "unsupported URI scheme: {scheme}"),
+            Self::InvalidHeader { name, message } => {
+                write!(f, "invalid http_options header '{name}': {message}")
+            }
+            Self::InvalidProxy(message) => write!(f, "invalid http_options proxy: {message}"),
+            Self::ClientBuild(message) => {
+                write!(f, "failed to build HTTP client from http_options: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for NativeRetrieverError {}
+
+/// Headers/proxy/redirect-limit configuration applied to every fetch made by
+/// [`NativeHttpRetriever`], derived from a Ruby `HttpOptions` instance (see
+/// `options::native_http_config`). Kept independent of `HttpOptions` itself so
+/// `retriever.rs` doesn't need to depend on the Ruby-wrapper type directly.
+#[derive(Debug, Clone)]
+pub struct NativeHttpConfig {
+    pub headers: HashMap<String, String>,
+    pub proxy: Option<String>,
+    pub max_redirects: Option<u32>,
+    // A `None` `max_retries` means "no retries", preserving the
+    // previously-unconditional single-attempt behavior.
+    pub max_retries: Option<u32>,
+    pub retry_backoff: Option<f64>,
+    pub max_concurrent: Option<u32>,
+    // Used as the retriever's `cache_dir` when `Registry.new(cache_dir: ...)`
+    // is not given; an explicit `Registry.new` `cache_dir` takes precedence.
+    pub cache_dir: Option<PathBuf>,
+    pub cache_enabled: bool,
+}
+
+impl Default for NativeHttpConfig {
+    fn default() -> Self {
+        Self {
+            headers: HashMap::new(),
+            proxy: None,
+            max_redirects: None,
+            max_retries: None,
+            retry_backoff: None,
+            max_concurrent: None,
+            cache_dir: None,
+            // Matches the behavior before `cache_enabled` existed: always cache.
+            cache_enabled: true,
+        }
+    }
+}
+
+/// Fetches `$ref`s over HTTP(S), caching responses on disk under `cache_dir`.
+///
+/// Installed via `retriever: :http` on `Registry`, this covers the common case
+/// of resolving remote references without a Ruby callback. It holds no Ruby
+/// values, so unlike [`RubyRetriever`] it needs no GC rooting.
+pub struct NativeHttpRetriever {
+    cache_dir: PathBuf,
+    cache_enabled: bool,
+    client: reqwest::blocking::Client,
+    max_retries: u32,
+    retry_backoff: Duration,
+    concurrency: Option<Semaphore>,
+}
+
+/// A cached response body plus the validators (`ETag`/`Last-Modified`) and
+/// freshness lifetime (`Cache-Control: max-age`) needed to revalidate it
+/// instead of re-fetching unconditionally.
+struct CacheEntry {
+    body: JsonValue,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    max_age: Option<u64>,
+    fetched_at: u64,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, now: u64) -> bool {
+        match self.max_age {
+            Some(max_age) => now.saturating_sub(self.fetched_at) < max_age,
+            None => false,
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        serde_json::json!({
+            "body": self.body,
+            "etag": self.etag,
+            "last_modified": self.last_modified,
+            "max_age": self.max_age,
+            "fetched_at": self.fetched_at,
+        })
+    }
+
+    fn from_json(value: JsonValue) -> Option<Self> {
+        let object = value.as_object()?;
+        Some(Self {
+            body: object.get("body")?.clone(),
+            etag: object.get("etag").and_then(JsonValue::as_str).map(str::to_owned),
+            last_modified: object
+                .get("last_modified")
+                .and_then(JsonValue::as_str)
+                .map(str::to_owned),
+            max_age: object.get("max_age").and_then(JsonValue::as_u64),
+            fetched_at: object.get("fetched_at").and_then(JsonValue::as_u64)?,
+        })
+    }
+}
+
+/// The validators/freshness lifetime read off a successful (non-304) fetch,
+/// paired with the response body by [`NativeHttpRetriever::retrieve`].
+struct ResponseMeta {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    max_age: Option<u64>,
+}
+
+impl ResponseMeta {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let etag = headers
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        let last_modified = headers
+            .get(reqwest::header::LAST_MODIFIED)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_owned);
+        let max_age = headers
+            .get(reqwest::header::CACHE_CONTROL)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|value| {
+                value.split(',').find_map(|directive| {
+                    directive.trim().strip_prefix("max-age=")?.parse::<u64>().ok()
+                })
+            });
+        Self {
+            etag,
+            last_modified,
+            max_age,
+        }
+    }
+}
+
+fn now_unix() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Outcome of a conditional (or unconditional) fetch attempt.
+enum FetchOutcome {
+    NotModified,
+    Body(Vec<u8>, ResponseMeta),
+}
+
+/// A simple counting semaphore limiting how many fetches
+/// [`NativeHttpRetriever`] runs at once, per `http_options.max_concurrent`.
+struct Semaphore {
+    state: Mutex<usize>,
+    available: Condvar,
+}
+
+impl Semaphore {
+    fn new(permits: u32) -> Self {
+        Self {
+            state: Mutex::new(permits as usize),
+            available: Condvar::new(),
+        }
+    }
+
+    fn acquire(&self) -> SemaphorePermit<'_> {
+        let mut remaining = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        while *remaining == 0 {
+            remaining = self
+                .available
+                .wait(remaining)
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+        }
+        *remaining -= 1;
+        SemaphorePermit { semaphore: self }
+    }
+}
+
+struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        let mut remaining = self
+            .semaphore
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *remaining += 1;
+        self.semaphore.available.notify_one();
+    }
+}
+
+/// A pseudo-random value in `[0.0, 1.0)`, used only to jitter retry backoff
+/// delays — no need for cryptographic quality, so this avoids pulling in a
+/// `rand` dependency for one call site.
+fn jitter_unit() -> f64 {
+    static COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| u64::from(d.subsec_nanos()))
+        .unwrap_or(0);
+    let count = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let mixed = (nanos ^ count.wrapping_mul(0x9E37_79B9_7F4A_7C15)).wrapping_mul(0x2545_F491_4F6C_DD1D);
+    (mixed >> 40) as f64 / (1u64 << 24) as f64
+}
+
+/// Exponential backoff with ±20% jitter, capped at 30s: `base * 2^attempt`,
+/// jittered and clamped.
+fn backoff_delay(base: Duration, attempt: u32) -> Duration {
+    const CAP: Duration = Duration::from_secs(30);
+    let exponential = base.saturating_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX));
+    let capped = exponential.min(CAP);
+    let factor = 1.0 + 0.2 * (jitter_unit() * 2.0 - 1.0);
+    capped.mul_f64(factor).min(CAP)
+}
+
+/// Delay requested by a `Retry-After` response header, if present and given
+/// in seconds (the HTTP-date form isn't handled).
+fn retry_after_delay(response: &reqwest::blocking::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+}
+
+impl NativeHttpRetriever {
+    pub fn new(cache_dir: PathBuf, http_config: &NativeHttpConfig) -> Result<Self, NativeRetrieverError> {
+        let mut builder = reqwest::blocking::Client::builder();
+
+        if !http_config.headers.is_empty() {
+            let mut header_map = reqwest::header::HeaderMap::new();
+            for (name, value) in &http_config.headers {
+                let header_name =
+                    reqwest::header::HeaderName::from_bytes(name.as_bytes()).map_err(|error| {
+                        NativeRetrieverError::InvalidHeader {
+                            name: name.clone(),
+                            message: error.to_string(),
+                        }
+                    })?;
+                let header_value =
+                    reqwest::header::HeaderValue::from_str(value).map_err(|error| {
+                        NativeRetrieverError::InvalidHeader {
+                            name: name.clone(),
+                            message: error.to_string(),
+                        }
+                    })?;
+                header_map.insert(header_name, header_value);
+            }
+            builder = builder.default_headers(header_map);
+        }
+
+        if let Some(proxy) = &http_config.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|error| NativeRetrieverError::InvalidProxy(error.to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+
+        if let Some(max_redirects) = http_config.max_redirects {
+            builder = builder.redirect(reqwest::redirect::Policy::limited(max_redirects as usize));
+        }
+
+        let client = builder
+            .build()
+            .map_err(|error| NativeRetrieverError::ClientBuild(error.to_string()))?;
+
+        let retry_backoff = Duration::from_secs_f64(http_config.retry_backoff.unwrap_or(0.5));
+        let concurrency = http_config.max_concurrent.map(Semaphore::new);
+
+        Ok(Self {
+            cache_dir,
+            cache_enabled: http_config.cache_enabled,
+            client,
+            max_retries: http_config.max_retries.unwrap_or(0),
+            retry_backoff,
+            concurrency,
+        })
+    }
+
+    pub fn cache_dir(&self) -> &std::path::Path {
+        &self.cache_dir
+    }
+
+    fn cache_path(&self, uri: &str) -> PathBuf {
+        self.cache_dir.join(format!("{:016x}.json", fnv1a(uri)))
+    }
+
+    fn read_entry(&self, uri: &str) -> Option<CacheEntry> {
+        let contents = fs::read(self.cache_path(uri)).ok()?;
+        let value = serde_json::from_slice(&contents).ok()?;
+        CacheEntry::from_json(value)
+    }
+
+    fn write_entry(&self, uri: &str, entry: &CacheEntry) {
+        if fs::create_dir_all(&self.cache_dir).is_ok() {
+            if let Ok(contents) = serde_json::to_vec(&entry.to_json()) {
+                let _ = fs::write(self.cache_path(uri), contents);
+            }
+        }
+    }
+
+    /// Fetches `uri_str`, retrying idempotent GETs up to `max_retries` times
+    /// on connection errors and 429/503 responses with exponential backoff
+    /// (honoring `Retry-After` when the server sends one), limited to
+    /// `max_concurrent` in-flight fetches. When `conditional` is given, sends
+    /// `If-None-Match`/`If-Modified-Since` so the server can answer `304`.
+    fn fetch_with_retry(
+        &self,
+        uri_str: &str,
+        conditional: Option<&CacheEntry>,
+    ) -> Result<FetchOutcome, NativeRetrieverError> {
+        let _permit = self.concurrency.as_ref().map(Semaphore::acquire);
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get(uri_str);
+            if let Some(entry) = conditional {
+                if let Some(etag) = &entry.etag {
+                    request = request.header(reqwest::header::IF_NONE_MATCH, etag.as_str());
+                }
+                if let Some(last_modified) = &entry.last_modified {
+                    request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified.as_str());
+                }
+            }
+
+            match request.send() {
+                Ok(response) if response.status() == reqwest::StatusCode::NOT_MODIFIED => {
+                    return Ok(FetchOutcome::NotModified);
+                }
+                Ok(response) if response.status().is_success() => {
+                    let meta = ResponseMeta::from_headers(response.headers());
+                    let bytes = response
+                        .bytes()
+                        .map(|bytes| bytes.to_vec())
+                        .map_err(|error| NativeRetrieverError::Fetch {
+                            uri: uri_str.to_owned(),
+                            message: error.to_string(),
+                        })?;
+                    return Ok(FetchOutcome::Body(bytes, meta));
+                }
+                Ok(response)
+                    if attempt < self.max_retries && is_retryable_status(response.status()) =>
+                {
+                    let delay = retry_after_delay(&response)
+                        .unwrap_or_else(|| backoff_delay(self.retry_backoff, attempt));
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Ok(response) => {
+                    let message = response
+                        .error_for_status()
+                        .expect_err("non-success status")
+                        .to_string();
+                    return Err(NativeRetrieverError::Fetch {
+                        uri: uri_str.to_owned(),
+                        message,
+                    });
+                }
+                Err(error) if attempt < self.max_retries && (error.is_connect() || error.is_timeout()) => {
+                    let delay = backoff_delay(self.retry_backoff, attempt);
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Err(error) => {
+                    return Err(NativeRetrieverError::Fetch {
+                        uri: uri_str.to_owned(),
+                        message: error.to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    fn parse_body(uri_str: &str, bytes: &[u8]) -> Result<JsonValue, NativeRetrieverError> {
+        serde_json::from_slice(bytes).map_err(|source| NativeRetrieverError::Parse {
+            uri: uri_str.to_owned(),
+            source,
+        })
+    }
+}
+
+impl Retrieve for NativeHttpRetriever {
+    fn retrieve(
+        &self,
+        uri: &Uri<String>,
+    ) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
+        let uri_str = uri.as_str();
+        match uri.scheme().as_str() {
+            "http" | "https" => {
+                if !self.cache_enabled {
+                    return match self.fetch_with_retry(uri_str, None)? {
+                        FetchOutcome::Body(bytes, _meta) => Self::parse_body(uri_str, &bytes),
+                        FetchOutcome::NotModified => Err(NativeRetrieverError::Fetch {
+                            uri: uri_str.to_owned(),
+                            message: "server returned 304 Not Modified for an unconditional request".to_string(),
+                        }),
+                    }
+                    .map_err(|e| Box::new(e) as _);
+                }
+
+                let now = now_unix();
+                if let Some(entry) = self.read_entry(uri_str) {
+                    if entry.is_fresh(now) {
+                        return Ok(entry.body);
+                    }
+
+                    return match self.fetch_with_retry(uri_str, Some(&entry)) {
+                        Ok(FetchOutcome::NotModified) => {
+                            let refreshed = CacheEntry {
+                                fetched_at: now,
+                                ..entry
+                            };
+                            self.write_entry(uri_str, &refreshed);
+                            Ok(refreshed.body)
+                        }
+                        Ok(FetchOutcome::Body(bytes, meta)) => {
+                            let body = Self::parse_body(uri_str, &bytes)?;
+                            let fresh_entry = CacheEntry {
+                                body: body.clone(),
+                                etag: meta.etag,
+                                last_modified: meta.last_modified,
+                                max_age: meta.max_age,
+                                fetched_at: now,
+                            };
+                            self.write_entry(uri_str, &fresh_entry);
+                            Ok(body)
+                        }
+                        Err(e) => Err(e),
+                    }
+                    .map_err(|e| Box::new(e) as _);
+                }
+
+                match self.fetch_with_retry(uri_str, None)? {
+                    FetchOutcome::Body(bytes, meta) => {
+                        let body = Self::parse_body(uri_str, &bytes).map_err(|e| Box::new(e) as _)?;
+                        let entry = CacheEntry {
+                            body: body.clone(),
+                            etag: meta.etag,
+                            last_modified: meta.last_modified,
+                            max_age: meta.max_age,
+                            fetched_at: now,
+                        };
+                        self.write_entry(uri_str, &entry);
+                        Ok(body)
+                    }
+                    FetchOutcome::NotModified => Err(Box::new(NativeRetrieverError::Fetch {
+                        uri: uri_str.to_owned(),
+                        message: "server returned 304 Not Modified for an unconditional request".to_string(),
+                    }) as _),
+                }
+            }
+            other => Err(Box::new(NativeRetrieverError::UnsupportedScheme(
+                other.to_string(),
+            ))),
+        }
+    }
+}
+
+/// Dispatches to whichever kind of retriever the caller configured, so
+/// `ValidationOptions::with_retriever` only ever sees a single concrete type.
+pub enum AnyRetriever {
+    Ruby(RubyRetriever),
+    Native(NativeHttpRetriever),
+}
+
+impl Retrieve for AnyRetriever {
+    fn retrieve(
+        &self,
+        uri: &Uri<String>,
+    ) -> Result<JsonValue, Box<dyn std::error::Error + Send + Sync>> {
+        match self {
+            Self::Ruby(retriever) => retriever.retrieve(uri),
+            Self::Native(retriever) => retriever.retrieve(uri),
+        }
+    }
+}
+
+pub fn default_cache_dir() -> PathBuf {
+    dirs::cache_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("jsonschema")
+}
+
+/// Tiny FNV-1a hash, used only to derive stable, filesystem-safe cache keys.
+fn fnv1a(data: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+    data.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
 }
@@ -22,11 +22,94 @@ static BIG_DECIMAL_CLASS: Lazy<RClass> = Lazy::new(|ruby| {
     cls
 });
 
-const RECURSION_LIMIT: u16 = 255;
+/// Default for [`ConversionOptions::max_depth`]. The work-stack traversal in
+/// [`to_value_recursive`] is bounded by heap memory rather than the native
+/// call stack, so this is far larger than the old fixed 255-level cap it
+/// replaces — but a self-referential Ruby `Array`/`Hash` (`a = []; a << a`)
+/// would otherwise grow the stack forever, so `max_depth` stays a generous
+/// finite default rather than `None` (unlimited).
+pub const DEFAULT_MAX_DEPTH: u32 = 100_000;
+
+/// What to do with a Ruby String whose bytes aren't valid UTF-8 (typically an
+/// ASCII-8BIT/binary string) when converting it to a `serde_json::Value`.
+/// `Error`, the default, preserves the historical behavior of raising
+/// `EncodingError`. `Base64`/`Hex` instead emit the raw bytes encoded as a
+/// JSON string, so a schema with `{"type":"string","contentEncoding":"base64"}`
+/// can validate binary Ruby data directly rather than rejecting it at the
+/// conversion step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BinaryStringPolicy {
+    #[default]
+    Error,
+    Base64,
+    Hex,
+}
+
+/// Method names tried, in order, as a conversion-protocol fallback when a
+/// Ruby object is none of the natively-supported types (primitives, Array,
+/// Hash, BigDecimal). The first method the object responds to is called and
+/// its return value is recursively converted in the object's place, so
+/// domain objects, `Time`/`Date`, `Set`, `Struct`, or Rails-style models can
+/// validate against a schema without the caller pre-serializing them by hand.
+pub const DEFAULT_CONVERSION_METHODS: &[&str] = &["to_json_schema_value", "as_json", "to_h"];
+
+/// Knobs for the Ruby-to-JSON conversion entry points ([`to_value`] and
+/// friends), bundled together since they're threaded identically through
+/// every recursive call. With `Default`, non-UTF-8 strings raise and the
+/// [`DEFAULT_CONVERSION_METHODS`] are tried as the conversion-protocol fallback.
+#[derive(Debug, Clone, Copy)]
+pub struct ConversionOptions<'a> {
+    pub binary_policy: BinaryStringPolicy,
+    pub conversion_methods: &'a [&'a str],
+    /// Maximum Array/Hash nesting depth, or `None` for unbounded. See
+    /// [`DEFAULT_MAX_DEPTH`] for why the default is finite rather than
+    /// `None` despite the traversal no longer recursing on the Rust stack.
+    pub max_depth: Option<u32>,
+}
+
+impl Default for ConversionOptions<'static> {
+    fn default() -> Self {
+        ConversionOptions {
+            binary_policy: BinaryStringPolicy::default(),
+            conversion_methods: DEFAULT_CONVERSION_METHODS,
+            max_depth: Some(DEFAULT_MAX_DEPTH),
+        }
+    }
+}
 
 #[inline]
 pub fn to_value(ruby: &Ruby, value: Value) -> Result<JsonValue, Error> {
-    to_value_recursive(ruby, value, 0)
+    to_value_with_options(ruby, value, ConversionOptions::default())
+}
+
+/// Same as [`to_value`], but lets the caller choose how non-UTF-8 Ruby
+/// strings are handled instead of always raising.
+#[inline]
+pub fn to_value_with_policy(
+    ruby: &Ruby,
+    value: Value,
+    binary_policy: BinaryStringPolicy,
+) -> Result<JsonValue, Error> {
+    to_value_with_options(
+        ruby,
+        value,
+        ConversionOptions {
+            binary_policy,
+            ..ConversionOptions::default()
+        },
+    )
+}
+
+/// Same as [`to_value`], but lets the caller choose the full
+/// [`ConversionOptions`] (binary string policy and conversion-protocol
+/// method names) instead of only the defaults.
+#[inline]
+pub fn to_value_with_options(
+    ruby: &Ruby,
+    value: Value,
+    options: ConversionOptions<'_>,
+) -> Result<JsonValue, Error> {
+    to_value_recursive(ruby, value, options)
 }
 
 /// Convert a Ruby value in schema position to a `serde_json::Value`.
@@ -49,125 +132,345 @@ pub fn to_schema_value(ruby: &Ruby, value: Value) -> Result<JsonValue, Error> {
             }
         }
     }
-    to_value_typed(ruby, value, value_type, 0)
+    to_value_recursive(ruby, value, ConversionOptions::default())
+}
+
+/// A still-open Array/Hash on the explicit traversal stack used by
+/// [`to_value_recursive`]: children are converted one at a time and attached
+/// here, rather than the function recursing on the Rust call stack, so
+/// nesting depth is bounded by heap memory (see [`ConversionOptions::max_depth`])
+/// instead of by native stack space.
+enum ToValueFrame {
+    Array {
+        source: RArray,
+        len: usize,
+        next_index: usize,
+        out: Vec<JsonValue>,
+    },
+    /// Keys are resolved eagerly (cheap, non-recursive) when the frame is
+    /// created; only the paired values are converted lazily, one per
+    /// traversal step, via `entries[next_index]`.
+    Object {
+        entries: Vec<(String, Value)>,
+        next_index: usize,
+        out: Map<String, JsonValue>,
+    },
 }
 
-fn to_value_recursive(ruby: &Ruby, value: Value, depth: u16) -> Result<JsonValue, Error> {
-    if value.is_nil() {
-        return Ok(JsonValue::Null);
+impl ToValueFrame {
+    /// Pull this frame's next not-yet-converted child, or `None` once every
+    /// child has been attached.
+    fn next_child(&mut self, ruby: &Ruby) -> Result<Option<Value>, Error> {
+        match self {
+            ToValueFrame::Array {
+                source,
+                len,
+                next_index,
+                ..
+            } => {
+                if *next_index >= *len {
+                    return Ok(None);
+                }
+                let idx = isize::try_from(*next_index).map_err(|_| {
+                    Error::new(
+                        ruby.exception_arg_error(),
+                        "Array index exceeds supported range",
+                    )
+                })?;
+                // Do not use `RArray::as_slice` here: the caller converts this
+                // child before asking for the next one, and that conversion
+                // may call Ruby APIs for nested values — `as_slice` borrows
+                // Ruby-managed memory that must not be held across Ruby calls/GC.
+                let item: Value = source.entry(idx)?;
+                *next_index += 1;
+                Ok(Some(item))
+            }
+            ToValueFrame::Object {
+                entries,
+                next_index,
+                ..
+            } => {
+                if *next_index >= entries.len() {
+                    return Ok(None);
+                }
+                let (_, value) = entries[*next_index];
+                *next_index += 1;
+                Ok(Some(value))
+            }
+        }
     }
 
-    // SAFETY: We're reading the type tag of a valid Ruby value
-    #[allow(unsafe_code)]
-    let value_type = unsafe { RB_TYPE(value.as_raw()) };
+    /// Attach a just-finished child's conversion result, keyed by whichever
+    /// child [`next_child`] most recently handed out.
+    fn attach_last(&mut self, json: JsonValue) {
+        match self {
+            ToValueFrame::Array { out, .. } => out.push(json),
+            ToValueFrame::Object {
+                entries,
+                next_index,
+                out,
+            } => {
+                let key = entries[*next_index - 1].0.clone();
+                out.insert(key, json);
+            }
+        }
+    }
+
+    fn into_json(self) -> JsonValue {
+        match self {
+            ToValueFrame::Array { out, .. } => JsonValue::Array(out),
+            ToValueFrame::Object { out, .. } => JsonValue::Object(out),
+        }
+    }
+}
 
-    to_value_typed(ruby, value, value_type, depth)
+/// One step of converting a single Ruby value: either a finished leaf (nil,
+/// primitives, strings, symbols, BigDecimal, or a value resolved through the
+/// `to_json_schema_value`/`as_json`/`to_h` conversion-protocol fallback,
+/// which is followed in a loop right here since it doesn't grow the
+/// traversal stack) or a still-open Array/Hash whose children the driver
+/// loop below pushes as a [`ToValueFrame`] instead of recursing into.
+enum ToValueStep {
+    Done(JsonValue),
+    Array(RArray),
+    Object(Vec<(String, Value)>),
 }
 
-fn to_value_typed(
+fn to_value_leaf(
     ruby: &Ruby,
-    value: Value,
-    value_type: ruby_value_type,
-    depth: u16,
-) -> Result<JsonValue, Error> {
-    match value_type {
-        ruby_value_type::RUBY_T_TRUE => Ok(JsonValue::Bool(true)),
-        ruby_value_type::RUBY_T_FALSE => Ok(JsonValue::Bool(false)),
-        ruby_value_type::RUBY_T_FIXNUM | ruby_value_type::RUBY_T_BIGNUM => {
-            convert_integer(ruby, value)
-        }
-        ruby_value_type::RUBY_T_FLOAT => {
-            let f = f64::try_convert(value)?;
-            Number::from_f64(f).map(JsonValue::Number).ok_or_else(|| {
-                Error::new(
-                    ruby.exception_arg_error(),
-                    "Cannot convert NaN or Infinity to JSON",
-                )
-            })
+    mut value: Value,
+    options: ConversionOptions<'_>,
+) -> Result<ToValueStep, Error> {
+    loop {
+        if value.is_nil() {
+            return Ok(ToValueStep::Done(JsonValue::Null));
         }
-        ruby_value_type::RUBY_T_STRING => {
-            let Some(rstring) = RString::from_value(value) else {
-                unreachable!("We checked the type tag")
-            };
-            // SAFETY: rstring is valid and we're in Ruby VM context
-            #[allow(unsafe_code)]
-            let bytes = unsafe { rstring.as_slice() };
-            match std::str::from_utf8(bytes) {
-                Ok(s) => Ok(JsonValue::String(s.to_owned())),
-                Err(_) => Err(Error::new(
-                    ruby.exception_encoding_error(),
-                    "String is not valid UTF-8",
-                )),
+        // SAFETY: We're reading the type tag of a valid Ruby value
+        #[allow(unsafe_code)]
+        let value_type = unsafe { RB_TYPE(value.as_raw()) };
+        match value_type {
+            ruby_value_type::RUBY_T_TRUE => return Ok(ToValueStep::Done(JsonValue::Bool(true))),
+            ruby_value_type::RUBY_T_FALSE => {
+                return Ok(ToValueStep::Done(JsonValue::Bool(false)))
             }
-        }
-        ruby_value_type::RUBY_T_SYMBOL => {
-            let Some(sym) = Symbol::from_value(value) else {
-                unreachable!("We checked the type tag")
-            };
-            let name = sym.name()?;
-            Ok(JsonValue::String(name.to_string()))
-        }
-        ruby_value_type::RUBY_T_ARRAY => {
-            if depth >= RECURSION_LIMIT {
-                return Err(Error::new(
-                    ruby.exception_arg_error(),
-                    format!("Exceeded maximum nesting depth ({RECURSION_LIMIT})"),
-                ));
+            ruby_value_type::RUBY_T_FIXNUM | ruby_value_type::RUBY_T_BIGNUM => {
+                return Ok(ToValueStep::Done(convert_integer(ruby, value)?));
             }
-            let Some(arr) = RArray::from_value(value) else {
-                unreachable!("We checked the type tag")
-            };
-            let len = arr.len();
-            let mut json_arr = Vec::with_capacity(len);
-            // Do not use `RArray::as_slice` here: recursive conversion may call
-            // Ruby APIs for nested values, and `as_slice` borrows Ruby-managed
-            // memory that must not be held across Ruby calls/GC.
-            for idx in 0..len {
-                let idx = isize::try_from(idx).map_err(|_| {
-                    Error::new(
-                        ruby.exception_arg_error(),
-                        "Array index exceeds supported range",
-                    )
+            ruby_value_type::RUBY_T_FLOAT => {
+                let f = f64::try_convert(value)?;
+                return Number::from_f64(f)
+                    .map(|n| ToValueStep::Done(JsonValue::Number(n)))
+                    .ok_or_else(|| {
+                        Error::new(
+                            ruby.exception_arg_error(),
+                            "Cannot convert NaN or Infinity to JSON",
+                        )
+                    });
+            }
+            ruby_value_type::RUBY_T_STRING => {
+                let Some(rstring) = RString::from_value(value) else {
+                    unreachable!("We checked the type tag")
+                };
+                // SAFETY: rstring is valid and we're in Ruby VM context
+                #[allow(unsafe_code)]
+                let bytes = unsafe { rstring.as_slice() };
+                return match std::str::from_utf8(bytes) {
+                    Ok(s) => Ok(ToValueStep::Done(JsonValue::String(s.to_owned()))),
+                    Err(_) => match options.binary_policy {
+                        BinaryStringPolicy::Error => Err(Error::new(
+                            ruby.exception_encoding_error(),
+                            "String is not valid UTF-8",
+                        )),
+                        BinaryStringPolicy::Base64 => {
+                            Ok(ToValueStep::Done(JsonValue::String(base64_encode(bytes))))
+                        }
+                        BinaryStringPolicy::Hex => {
+                            Ok(ToValueStep::Done(JsonValue::String(hex_encode(bytes))))
+                        }
+                    },
+                };
+            }
+            ruby_value_type::RUBY_T_SYMBOL => {
+                let Some(sym) = Symbol::from_value(value) else {
+                    unreachable!("We checked the type tag")
+                };
+                let name = sym.name()?;
+                return Ok(ToValueStep::Done(JsonValue::String(name.to_string())));
+            }
+            ruby_value_type::RUBY_T_ARRAY => {
+                let Some(arr) = RArray::from_value(value) else {
+                    unreachable!("We checked the type tag")
+                };
+                return Ok(ToValueStep::Array(arr));
+            }
+            ruby_value_type::RUBY_T_HASH => {
+                let Some(hash) = RHash::from_value(value) else {
+                    unreachable!("We checked the type tag")
+                };
+                let mut entries = Vec::with_capacity(hash.len());
+                hash.foreach(|key: Value, val: Value| {
+                    let key_str = hash_key_to_string(ruby, key)?;
+                    entries.push((key_str, val));
+                    Ok(magnus::r_hash::ForEach::Continue)
                 })?;
-                let item: Value = arr.entry(idx)?;
-                json_arr.push(to_value_recursive(ruby, item, depth + 1)?);
+                return Ok(ToValueStep::Object(entries));
             }
-            Ok(JsonValue::Array(json_arr))
-        }
-        ruby_value_type::RUBY_T_HASH => {
-            if depth >= RECURSION_LIMIT {
+            ruby_value_type::RUBY_T_DATA
+                if value.is_kind_of(ruby.get_inner(&BIG_DECIMAL_CLASS)) =>
+            {
+                return Ok(ToValueStep::Done(convert_big_decimal(ruby, value)?));
+            }
+            _ => {
+                let mut redirected = false;
+                for method in options.conversion_methods {
+                    let responds: bool = value.funcall("respond_to?", (*method,))?;
+                    if responds {
+                        value = value.funcall(*method, ())?;
+                        redirected = true;
+                        break;
+                    }
+                }
+                if redirected {
+                    continue;
+                }
+                let class = value.class();
+                #[allow(unsafe_code)]
+                let class_name = unsafe { class.name() };
                 return Err(Error::new(
-                    ruby.exception_arg_error(),
-                    format!("Exceeded maximum nesting depth ({RECURSION_LIMIT})"),
+                    ruby.exception_type_error(),
+                    format!("Unsupported type: '{class_name}'"),
                 ));
             }
-            let Some(hash) = RHash::from_value(value) else {
-                unreachable!("We checked the type tag")
-            };
-            let mut map = Map::with_capacity(hash.len());
-            hash.foreach(|key: Value, val: Value| {
-                let key_str = hash_key_to_string(ruby, key)?;
-                let json_val = to_value_recursive(ruby, val, depth + 1)?;
-                map.insert(key_str, json_val);
-                Ok(magnus::r_hash::ForEach::Continue)
-            })?;
-            Ok(JsonValue::Object(map))
-        }
-        ruby_value_type::RUBY_T_DATA if value.is_kind_of(ruby.get_inner(&BIG_DECIMAL_CLASS)) => {
-            convert_big_decimal(ruby, value)
         }
-        _ => {
-            let class = value.class();
-            #[allow(unsafe_code)]
-            let class_name = unsafe { class.name() };
-            Err(Error::new(
-                ruby.exception_type_error(),
-                format!("Unsupported type: '{class_name}'"),
-            ))
+    }
+}
+
+/// Ruby-to-JSON conversion driver. Array/Hash children are converted via an
+/// explicit `Vec<ToValueFrame>` work-stack rather than Rust recursion: each
+/// push corresponds to entering a container, each pop to finishing one, so
+/// nesting depth is bounded by [`ConversionOptions::max_depth`] (checked
+/// against the stack's length) and by available heap memory, not by native
+/// stack space.
+fn to_value_recursive(
+    ruby: &Ruby,
+    value: Value,
+    options: ConversionOptions<'_>,
+) -> Result<JsonValue, Error> {
+    let mut stack: Vec<ToValueFrame> = Vec::new();
+    let mut pending = value;
+
+    loop {
+        // Convert `pending`, entering new frames for any Array/Hash
+        // encountered along the way, until we land on a fully-converted
+        // `JsonValue` (a leaf, or an empty container popped right back off).
+        let mut completed = loop {
+            let step = to_value_leaf(ruby, pending, options)?;
+            let frame = match step {
+                ToValueStep::Done(json) => break json,
+                ToValueStep::Array(arr) => {
+                    if let Some(limit) = options.max_depth {
+                        if stack.len() as u32 >= limit {
+                            return Err(Error::new(
+                                ruby.exception_arg_error(),
+                                format!("Exceeded maximum nesting depth ({limit})"),
+                            ));
+                        }
+                    }
+                    let len = arr.len();
+                    ToValueFrame::Array {
+                        source: arr,
+                        len,
+                        next_index: 0,
+                        out: Vec::with_capacity(len),
+                    }
+                }
+                ToValueStep::Object(entries) => {
+                    if let Some(limit) = options.max_depth {
+                        if stack.len() as u32 >= limit {
+                            return Err(Error::new(
+                                ruby.exception_arg_error(),
+                                format!("Exceeded maximum nesting depth ({limit})"),
+                            ));
+                        }
+                    }
+                    let len = entries.len();
+                    ToValueFrame::Object {
+                        entries,
+                        next_index: 0,
+                        out: Map::with_capacity(len),
+                    }
+                }
+            };
+            stack.push(frame);
+            match stack.last_mut().expect("just pushed").next_child(ruby)? {
+                Some(child) => pending = child,
+                None => break stack.pop().expect("just pushed").into_json(),
+            }
+        };
+
+        // `completed` is a fully-converted JsonValue: attach it to the
+        // parent frame, pull that frame's next child, and keep popping
+        // finished frames until either a new child needs converting or the
+        // stack is empty (at which point `completed` is the final result).
+        loop {
+            let Some(frame) = stack.last_mut() else {
+                return Ok(completed);
+            };
+            frame.attach_last(completed);
+            match frame.next_child(ruby)? {
+                Some(child) => {
+                    pending = child;
+                    break;
+                }
+                None => {
+                    completed = stack.pop().expect("just matched Some(frame)").into_json();
+                }
+            }
         }
     }
 }
 
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard (padded) base64 encoding, used by [`BinaryStringPolicy::Base64`]
+/// for Ruby strings whose bytes aren't valid UTF-8.
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0];
+        let b1 = chunk.get(1).copied();
+        let b2 = chunk.get(2).copied();
+
+        out.push(BASE64_ALPHABET[(b0 >> 2) as usize] as char);
+        out.push(
+            BASE64_ALPHABET[(((b0 & 0b11) << 4) | (b1.unwrap_or(0) >> 4)) as usize] as char,
+        );
+        out.push(match b1 {
+            Some(b1) => {
+                BASE64_ALPHABET[(((b1 & 0b1111) << 2) | (b2.unwrap_or(0) >> 6)) as usize] as char
+            }
+            None => '=',
+        });
+        out.push(match b2 {
+            Some(b2) => BASE64_ALPHABET[(b2 & 0b0011_1111) as usize] as char,
+            None => '=',
+        });
+    }
+    out
+}
+
+/// Lowercase hex encoding, used by [`BinaryStringPolicy::Hex`] for Ruby
+/// strings whose bytes aren't valid UTF-8.
+fn hex_encode(bytes: &[u8]) -> String {
+    use std::fmt::Write as _;
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
 /// Convert Ruby BigDecimal to JSON Number while preserving precision.
 #[inline]
 fn convert_big_decimal(ruby: &Ruby, value: Value) -> Result<JsonValue, Error> {
@@ -209,6 +512,14 @@ fn convert_integer(ruby: &Ruby, value: Value) -> Result<JsonValue, Error> {
     ))
 }
 
+/// Stringifies a Ruby hash key the way serde_json's `MapKeySerializer`
+/// stringifies non-string JSON object keys: strings and symbols pass through
+/// unchanged, integers (including bignums) print in decimal via the same
+/// arbitrary-precision path [`convert_integer`] uses for values, `true`/
+/// `false` become `"true"`/`"false"`, and floats use their canonical JSON
+/// numeric form. Two distinct Ruby keys that stringify the same way simply
+/// overwrite one another in the destination `Map`/`Hash`, last write wins,
+/// the same as any other duplicate-key insertion.
 #[inline]
 fn hash_key_to_string(ruby: &Ruby, key: Value) -> Result<String, Error> {
     #[allow(unsafe_code)]
@@ -235,42 +546,89 @@ fn hash_key_to_string(ruby: &Ruby, key: Value) -> Result<String, Error> {
                 return Ok(sym.name()?.to_string());
             }
         }
+        ruby_value_type::RUBY_T_TRUE => return Ok("true".to_owned()),
+        ruby_value_type::RUBY_T_FALSE => return Ok("false".to_owned()),
+        ruby_value_type::RUBY_T_FIXNUM | ruby_value_type::RUBY_T_BIGNUM => {
+            if let JsonValue::Number(n) = convert_integer(ruby, key)? {
+                return Ok(n.to_string());
+            }
+        }
+        ruby_value_type::RUBY_T_FLOAT => {
+            let f = f64::try_convert(key)?;
+            if let Some(n) = Number::from_f64(f) {
+                return Ok(n.to_string());
+            }
+        }
         _ => {}
     }
 
     Err(Error::new(
         ruby.exception_type_error(),
-        "Hash keys must be strings or symbols",
+        "Hash keys must be strings, symbols, integers, floats, or booleans",
     ))
 }
 
+/// How JSON numbers are materialized as Ruby objects. The default mode
+/// narrows a number to a Ruby `Float` when the decimal text round-trips
+/// through `f64` exactly, and only falls back to `BigDecimal` otherwise.
+/// `Lossless` skips that round-trip attempt entirely: every non-integer
+/// number is built straight from its original serde_json number text via
+/// `BigDecimal`, so the lexical form parsing it produced is always exactly
+/// reproducible by `serialize_to_ruby`/`to_value`. Integers are already
+/// arbitrary-precision either way (they go through `Kernel.Integer` on the
+/// decimal string, never through `f64`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberMode {
+    #[default]
+    Default,
+    Lossless,
+}
+
 #[inline]
 pub fn map_to_ruby(ruby: &Ruby, map: &Map<String, JsonValue>) -> Result<Value, Error> {
+    map_to_ruby_with_mode(ruby, map, NumberMode::default())
+}
+
+/// Same as [`map_to_ruby`], but lets the caller choose [`NumberMode`].
+pub fn map_to_ruby_with_mode(
+    ruby: &Ruby,
+    map: &Map<String, JsonValue>,
+    number_mode: NumberMode,
+) -> Result<Value, Error> {
     let rb_hash = ruby.hash_new_capa(map.len());
     for (k, v) in map {
-        rb_hash.aset(k.as_str(), value_to_ruby(ruby, v)?)?;
+        rb_hash.aset(k.as_str(), value_to_ruby_with_mode(ruby, v, number_mode)?)?;
     }
     Ok(rb_hash.as_value())
 }
 
 #[inline]
 pub fn value_to_ruby(ruby: &Ruby, value: &JsonValue) -> Result<Value, Error> {
+    value_to_ruby_with_mode(ruby, value, NumberMode::default())
+}
+
+/// Same as [`value_to_ruby`], but lets the caller choose [`NumberMode`].
+pub fn value_to_ruby_with_mode(
+    ruby: &Ruby,
+    value: &JsonValue,
+    number_mode: NumberMode,
+) -> Result<Value, Error> {
     match value {
         JsonValue::Null => Ok(ruby.qnil().as_value()),
         JsonValue::Bool(b) => Ok(ruby.into_value(*b)),
-        JsonValue::Number(n) => number_to_ruby(ruby, n),
+        JsonValue::Number(n) => number_to_ruby_with_mode(ruby, n, number_mode),
         JsonValue::String(s) => Ok(ruby.into_value(s.as_str())),
         JsonValue::Array(arr) => {
             let rb_arr = ruby.ary_new_capa(arr.len());
             for item in arr {
-                rb_arr.push(value_to_ruby(ruby, item)?)?;
+                rb_arr.push(value_to_ruby_with_mode(ruby, item, number_mode)?)?;
             }
             Ok(rb_arr.as_value())
         }
         JsonValue::Object(obj) => {
             let rb_hash = ruby.hash_new_capa(obj.len());
             for (k, v) in obj {
-                rb_hash.aset(k.as_str(), value_to_ruby(ruby, v)?)?;
+                rb_hash.aset(k.as_str(), value_to_ruby_with_mode(ruby, v, number_mode)?)?;
             }
             Ok(rb_hash.as_value())
         }
@@ -279,26 +637,46 @@ pub fn value_to_ruby(ruby: &Ruby, value: &JsonValue) -> Result<Value, Error> {
 
 #[inline]
 fn number_to_ruby(ruby: &Ruby, number: &Number) -> Result<Value, Error> {
+    number_to_ruby_with_mode(ruby, number, NumberMode::default())
+}
+
+#[inline]
+fn number_to_ruby_with_mode(
+    ruby: &Ruby,
+    number: &Number,
+    number_mode: NumberMode,
+) -> Result<Value, Error> {
     if let Some(i) = number.as_i64() {
         return Ok(ruby.into_value(i));
     }
     if let Some(u) = number.as_u64() {
         return Ok(ruby.integer_from_u64(u).as_value());
     }
-    number_string_to_ruby(ruby, &number.to_string())
+    number_string_to_ruby_with_mode(ruby, &number.to_string(), number_mode)
 }
 
 #[inline]
 fn number_string_to_ruby(ruby: &Ruby, number: &str) -> Result<Value, Error> {
+    number_string_to_ruby_with_mode(ruby, number, NumberMode::default())
+}
+
+#[inline]
+fn number_string_to_ruby_with_mode(
+    ruby: &Ruby,
+    number: &str,
+    number_mode: NumberMode,
+) -> Result<Value, Error> {
     if !number.contains(['.', 'e', 'E']) {
         return ruby.module_kernel().funcall("Integer", (number,));
     }
 
-    if let Ok(f) = number.parse::<f64>() {
-        if f.is_finite()
-            && Number::from_f64(f).is_some_and(|roundtrip| roundtrip.to_string() == number)
-        {
-            return Ok(ruby.into_value(f));
+    if number_mode == NumberMode::Default {
+        if let Ok(f) = number.parse::<f64>() {
+            if f.is_finite()
+                && Number::from_f64(f).is_some_and(|roundtrip| roundtrip.to_string() == number)
+            {
+                return Ok(ruby.into_value(f));
+            }
         }
     }
 
@@ -330,16 +708,21 @@ impl serde::ser::Error for RubySerError {
 #[derive(Clone, Copy)]
 struct RubySerializer<'a> {
     ruby: &'a Ruby,
+    number_mode: NumberMode,
 }
 
 impl<'a> RubySerializer<'a> {
     fn new(ruby: &'a Ruby) -> Self {
-        RubySerializer { ruby }
+        Self::new_with_mode(ruby, NumberMode::default())
+    }
+
+    fn new_with_mode(ruby: &'a Ruby, number_mode: NumberMode) -> Self {
+        RubySerializer { ruby, number_mode }
     }
 
     /// Parse a raw number string into a Ruby Integer, Float, or BigDecimal.
     fn parse_number(&self, s: &str) -> Result<Value, RubySerError> {
-        number_string_to_ruby(self.ruby, s)
+        number_string_to_ruby_with_mode(self.ruby, s, self.number_mode)
             .map_err(|e| RubySerError(format!("number conversion failed: {e}")))
     }
 }
@@ -508,6 +891,7 @@ impl<'a> serde::Serializer for RubySerializer<'a> {
         Ok(RubySeqSerializer {
             ruby: self.ruby,
             arr,
+            number_mode: self.number_mode,
         })
     }
 
@@ -542,6 +926,7 @@ impl<'a> serde::Serializer for RubySerializer<'a> {
             ruby: self.ruby,
             hash,
             next_key: None,
+            number_mode: self.number_mode,
         })
     }
 
@@ -553,6 +938,7 @@ impl<'a> serde::Serializer for RubySerializer<'a> {
         Ok(RubyStructSerializer {
             ruby: self.ruby,
             hash: self.ruby.hash_new_capa(len),
+            number_mode: self.number_mode,
         })
     }
 
@@ -566,6 +952,7 @@ impl<'a> serde::Serializer for RubySerializer<'a> {
         Ok(RubyStructSerializer {
             ruby: self.ruby,
             hash: self.ruby.hash_new_capa(len),
+            number_mode: self.number_mode,
         })
     }
 }
@@ -574,6 +961,7 @@ impl<'a> serde::Serializer for RubySerializer<'a> {
 struct RubySeqSerializer<'a> {
     ruby: &'a Ruby,
     arr: RArray,
+    number_mode: NumberMode,
 }
 
 impl serde::ser::SerializeSeq for RubySeqSerializer<'_> {
@@ -584,7 +972,7 @@ impl serde::ser::SerializeSeq for RubySeqSerializer<'_> {
         &mut self,
         value: &T,
     ) -> Result<(), RubySerError> {
-        let v = value.serialize(RubySerializer::new(self.ruby))?;
+        let v = value.serialize(RubySerializer::new_with_mode(self.ruby, self.number_mode))?;
         self.arr.push(v).map_err(serde::ser::Error::custom)
     }
 
@@ -646,6 +1034,7 @@ struct RubyMapSerializer<'a> {
     ruby: &'a Ruby,
     hash: RHash,
     next_key: Option<Value>,
+    number_mode: NumberMode,
 }
 
 impl serde::ser::SerializeMap for RubyMapSerializer<'_> {
@@ -653,7 +1042,16 @@ impl serde::ser::SerializeMap for RubyMapSerializer<'_> {
     type Error = RubySerError;
 
     fn serialize_key<T: ?Sized + serde::Serialize>(&mut self, key: &T) -> Result<(), RubySerError> {
-        self.next_key = Some(key.serialize(RubySerializer::new(self.ruby))?);
+        // Coerce through the same rules `hash_key_to_string` applies on the
+        // way back in, so a value built here and later fed through
+        // `to_value` round-trips to the same JSON object key rather than
+        // carrying a non-string Ruby key (e.g. an Integer) that `to_value`
+        // would otherwise stringify differently, or reject.
+        let key_value =
+            key.serialize(RubySerializer::new_with_mode(self.ruby, self.number_mode))?;
+        let key_string = hash_key_to_string(self.ruby, key_value)
+            .map_err(|e| RubySerError(format!("invalid hash key: {e}")))?;
+        self.next_key = Some(self.ruby.into_value(key_string));
         Ok(())
     }
 
@@ -665,7 +1063,7 @@ impl serde::ser::SerializeMap for RubyMapSerializer<'_> {
             .next_key
             .take()
             .expect("serialize_value called without serialize_key");
-        let val = value.serialize(RubySerializer::new(self.ruby))?;
+        let val = value.serialize(RubySerializer::new_with_mode(self.ruby, self.number_mode))?;
         self.hash.aset(key, val).map_err(serde::ser::Error::custom)
     }
 
@@ -678,6 +1076,7 @@ impl serde::ser::SerializeMap for RubyMapSerializer<'_> {
 struct RubyStructSerializer<'a> {
     ruby: &'a Ruby,
     hash: RHash,
+    number_mode: NumberMode,
 }
 
 impl serde::ser::SerializeStruct for RubyStructSerializer<'_> {
@@ -689,7 +1088,7 @@ impl serde::ser::SerializeStruct for RubyStructSerializer<'_> {
         key: &'static str,
         value: &T,
     ) -> Result<(), RubySerError> {
-        let val = value.serialize(RubySerializer::new(self.ruby))?;
+        let val = value.serialize(RubySerializer::new_with_mode(self.ruby, self.number_mode))?;
         let sym = self.ruby.sym_new(key);
         self.hash.aset(sym, val).map_err(serde::ser::Error::custom)
     }
@@ -718,7 +1117,17 @@ impl serde::ser::SerializeStructVariant for RubyStructSerializer<'_> {
 
 /// Serialize any [`serde::Serialize`] type directly to a Ruby [`Value`].
 pub fn serialize_to_ruby<T: serde::Serialize>(ruby: &Ruby, value: &T) -> Result<Value, Error> {
+    serialize_to_ruby_with_mode(ruby, value, NumberMode::default())
+}
+
+/// Same as [`serialize_to_ruby`], but lets the caller choose [`NumberMode`]
+/// for any `serde_json` arbitrary-precision numbers encountered along the way.
+pub fn serialize_to_ruby_with_mode<T: serde::Serialize>(
+    ruby: &Ruby,
+    value: &T,
+    number_mode: NumberMode,
+) -> Result<Value, Error> {
     value
-        .serialize(RubySerializer::new(ruby))
+        .serialize(RubySerializer::new_with_mode(ruby, number_mode))
         .map_err(|err| Error::new(ruby.exception_runtime_error(), err.to_string()))
 }
@@ -0,0 +1,460 @@
+//! Opt-in instance coercion: converting a scalar instance that arrived as a
+//! string (the shape everything takes in a form encoding or a log pipeline)
+//! into the type its subschema actually declares, before that subschema's
+//! own keyword validators run.
+//!
+//! TODO: wiring this in — a `with_coercion(true)` builder method on the
+//! (not-part-of-this-checkout) options builder, and a call from the
+//! (also not part of this checkout) `type`/`format` keyword validators that
+//! tries [`coerce`] when the raw instance fails to match but the
+//! subschema's `type`/`format` resolves to a non-passthrough [`Conversion`],
+//! substituting the coerced value and only falling through to a normal
+//! validation error when [`coerce`] itself fails — needs `compiler::Context`
+//! (to read the enclosing subschema's `type`/`format`) and the `type`/
+//! `format` validators themselves, none of which exist in this checkout.
+//! This module is the self-contained half: resolving which [`Conversion`]
+//! a subschema calls for, and actually performing it, needs only `&str`
+//! input and is fully exercised by the tests below.
+//!
+//! Scope note: [`normalize_rfc3339`] reformats a timestamp's punctuation and
+//! zero-padding but does not convert its offset to UTC — shifting the wall
+//! clock across an offset is meaningfully more arithmetic (and more ways to
+//! get subtly wrong) than this opt-in layer's stated job of "parse what
+//! showed up as a string", so it is left alone and passed through verbatim.
+//! Likewise, [`parse_with_pattern`] supports the `%Y`/`%m`/`%d`/`%H`/`%M`/
+//! `%S`/`%z` directives — enough for the common non-RFC3339 timestamps a log
+//! pipeline emits — not the full `strftime` grammar.
+
+/// Which conversion, if any, applies to a subschema's declared `type`/
+/// `format`, resolved once per subschema via [`Conversion::resolve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Conversion {
+    /// The subschema expects raw binary content: no coercion.
+    Bytes,
+    /// The subschema expects a plain string with no further shape: no
+    /// coercion.
+    String,
+    Integer,
+    Float,
+    Boolean,
+    /// `"format": "date-time"` with no explicit pattern: the raw string is
+    /// expected to already be RFC 3339 and is just normalized.
+    Timestamp,
+    /// `"format": "date-time"` plus a caller-supplied `strftime`-style
+    /// pattern with no UTC-offset directive, for non-RFC3339 timestamps.
+    TimestampFmt(String),
+    /// Same as [`Conversion::TimestampFmt`], but the pattern includes a
+    /// `%z` offset directive.
+    TimestampTZFmt(String),
+}
+
+impl Conversion {
+    /// Resolves the conversion a subschema calls for from its declared
+    /// `type` keyword, `format` keyword, and (only meaningful alongside
+    /// `format: "date-time"`) a caller-supplied `strftime`-style `pattern`
+    /// for timestamps that aren't RFC 3339.
+    #[must_use]
+    pub fn resolve(declared_type: Option<&str>, format: Option<&str>, pattern: Option<&str>) -> Self {
+        if format == Some("date-time") {
+            return match pattern {
+                Some(pattern) if pattern.contains("%z") => {
+                    Conversion::TimestampTZFmt(pattern.to_string())
+                }
+                Some(pattern) => Conversion::TimestampFmt(pattern.to_string()),
+                None => Conversion::Timestamp,
+            };
+        }
+        match declared_type {
+            Some("integer") => Conversion::Integer,
+            Some("number") => Conversion::Float,
+            Some("boolean") => Conversion::Boolean,
+            Some("string") | None => Conversion::String,
+            _ => Conversion::Bytes,
+        }
+    }
+
+    /// Whether this conversion is a no-op (the raw value is left as-is).
+    #[must_use]
+    pub fn is_passthrough(&self) -> bool {
+        matches!(self, Conversion::Bytes | Conversion::String)
+    }
+}
+
+/// A raw scalar successfully coerced to the shape its [`Conversion`]
+/// demanded.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoercedValue {
+    Integer(i64),
+    Float(f64),
+    Boolean(bool),
+    /// A normalized RFC 3339 timestamp string.
+    Timestamp(String),
+}
+
+/// Attempts `conversion` against `raw`.
+///
+/// Returns `Ok(None)` for [`Conversion::is_passthrough`] conversions (no
+/// coercion needed), `Ok(Some(value))` on success, and `Err(reason)` when
+/// `raw` can't be parsed the way `conversion` demands — the caller is
+/// expected to surface that reason as a normal validation error at the
+/// instance's location rather than treat it as a hard failure of the
+/// coercion layer itself.
+pub fn coerce(raw: &str, conversion: &Conversion) -> Result<Option<CoercedValue>, String> {
+    match conversion {
+        Conversion::Bytes | Conversion::String => Ok(None),
+        Conversion::Integer => parse_integer(raw).map(|v| Some(CoercedValue::Integer(v))),
+        Conversion::Float => parse_float(raw).map(|v| Some(CoercedValue::Float(v))),
+        Conversion::Boolean => parse_boolean(raw).map(|v| Some(CoercedValue::Boolean(v))),
+        Conversion::Timestamp => normalize_rfc3339(raw).map(|v| Some(CoercedValue::Timestamp(v))),
+        Conversion::TimestampFmt(pattern) => {
+            parse_with_pattern(raw, pattern).map(|v| Some(CoercedValue::Timestamp(v)))
+        }
+        Conversion::TimestampTZFmt(pattern) => {
+            parse_with_pattern(raw, pattern).map(|v| Some(CoercedValue::Timestamp(v)))
+        }
+    }
+}
+
+fn parse_integer(raw: &str) -> Result<i64, String> {
+    raw.trim()
+        .parse::<i64>()
+        .map_err(|err| format!("'{raw}' is not a valid integer: {err}"))
+}
+
+fn looks_like_json_number(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let mut pos = 0;
+    if bytes.first() == Some(&b'-') {
+        pos += 1;
+    }
+    let digits_start = pos;
+    while matches!(bytes.get(pos), Some(b'0'..=b'9')) {
+        pos += 1;
+    }
+    if pos == digits_start {
+        return false;
+    }
+    if bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        let frac_start = pos;
+        while matches!(bytes.get(pos), Some(b'0'..=b'9')) {
+            pos += 1;
+        }
+        if pos == frac_start {
+            return false;
+        }
+    }
+    if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+        pos += 1;
+        if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+            pos += 1;
+        }
+        let exp_start = pos;
+        while matches!(bytes.get(pos), Some(b'0'..=b'9')) {
+            pos += 1;
+        }
+        if pos == exp_start {
+            return false;
+        }
+    }
+    pos == bytes.len()
+}
+
+fn parse_float(raw: &str) -> Result<f64, String> {
+    let trimmed = raw.trim();
+    if !looks_like_json_number(trimmed) {
+        return Err(format!("'{raw}' is not a valid JSON number"));
+    }
+    trimmed
+        .parse::<f64>()
+        .map_err(|err| format!("'{raw}' is not a valid number: {err}"))
+}
+
+fn parse_boolean(raw: &str) -> Result<bool, String> {
+    match raw {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        other => Err(format!("'{other}' is not 'true' or 'false'")),
+    }
+}
+
+const DAYS_IN_MONTH: [u32; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u32, month: u32) -> Option<u32> {
+    let days = *DAYS_IN_MONTH.get((month.checked_sub(1)?) as usize)?;
+    if month == 2 && is_leap_year(year) {
+        Some(29)
+    } else {
+        Some(days)
+    }
+}
+
+fn parse_fixed_digits(s: &str, width: usize) -> Option<(u32, &str)> {
+    if s.len() < width || !s.as_bytes()[..width].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let (digits, rest) = s.split_at(width);
+    Some((digits.parse().ok()?, rest))
+}
+
+/// Validates (and reformats the punctuation of) an RFC 3339 `date-time`
+/// string. The offset is preserved verbatim — see the module doc's scope
+/// note on why no UTC conversion happens here.
+fn normalize_rfc3339(raw: &str) -> Result<String, String> {
+    let invalid = || format!("'{raw}' is not a valid RFC 3339 date-time");
+    let (year, rest) = parse_fixed_digits(raw, 4).ok_or_else(invalid)?;
+    let rest = rest.strip_prefix('-').ok_or_else(invalid)?;
+    let (month, rest) = parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+    let rest = rest.strip_prefix('-').ok_or_else(invalid)?;
+    let (day, rest) = parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+    let max_day = days_in_month(year, month).ok_or_else(invalid)?;
+    if month == 0 || month > 12 || day == 0 || day > max_day {
+        return Err(invalid());
+    }
+    let rest = rest
+        .strip_prefix('T')
+        .or_else(|| rest.strip_prefix('t'))
+        .ok_or_else(invalid)?;
+    let (hour, rest) = parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+    let rest = rest.strip_prefix(':').ok_or_else(invalid)?;
+    let (minute, rest) = parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+    let rest = rest.strip_prefix(':').ok_or_else(invalid)?;
+    let (second, mut rest) = parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+    if hour > 23 || minute > 59 || second > 60 {
+        // 60 allows a leap second, same as most RFC 3339 parsers.
+        return Err(invalid());
+    }
+
+    let mut fraction = String::new();
+    if let Some(after_dot) = rest.strip_prefix('.') {
+        let digit_count = after_dot.bytes().take_while(u8::is_ascii_digit).count();
+        if digit_count == 0 {
+            return Err(invalid());
+        }
+        fraction = format!(".{}", &after_dot[..digit_count]);
+        rest = &after_dot[digit_count..];
+    }
+
+    let offset = if rest == "Z" || rest == "z" {
+        "Z".to_string()
+    } else if let Some(sign) = rest.strip_prefix('+').map(|_| '+').or_else(|| rest.strip_prefix('-').map(|_| '-')) {
+        let body = &rest[1..];
+        let (offset_hour, body) = parse_fixed_digits(body, 2).ok_or_else(invalid)?;
+        let body = body.strip_prefix(':').ok_or_else(invalid)?;
+        let (offset_minute, body) = parse_fixed_digits(body, 2).ok_or_else(invalid)?;
+        if offset_hour > 23 || offset_minute > 59 || !body.is_empty() {
+            return Err(invalid());
+        }
+        format!("{sign}{offset_hour:02}:{offset_minute:02}")
+    } else {
+        return Err(invalid());
+    };
+
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}{fraction}{offset}"
+    ))
+}
+
+/// Parses `raw` against a `strftime`-style `pattern` supporting `%Y` (4
+/// digits), `%m`/`%d`/`%H`/`%M`/`%S` (2 digits each), and `%z` (`Z`, or a
+/// `+HH:MM`/`-HH:MM`/`+HHMM`/`-HHMM` offset), emitting a normalized RFC 3339
+/// string. Any other character in `pattern` must match `raw` literally.
+fn parse_with_pattern(raw: &str, pattern: &str) -> Result<String, String> {
+    let invalid = || format!("'{raw}' does not match pattern '{pattern}'");
+
+    let mut year = 1970u32;
+    let mut month = 1u32;
+    let mut day = 1u32;
+    let mut hour = 0u32;
+    let mut minute = 0u32;
+    let mut second = 0u32;
+    let mut offset = "Z".to_string();
+
+    let mut pat_chars = pattern.chars().peekable();
+    let mut cursor = raw;
+    while let Some(pat_char) = pat_chars.next() {
+        if pat_char == '%' {
+            let directive = pat_chars.next().ok_or_else(invalid)?;
+            match directive {
+                'Y' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 4).ok_or_else(invalid)?;
+                    year = value;
+                    cursor = rest;
+                }
+                'm' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 2).ok_or_else(invalid)?;
+                    month = value;
+                    cursor = rest;
+                }
+                'd' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 2).ok_or_else(invalid)?;
+                    day = value;
+                    cursor = rest;
+                }
+                'H' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 2).ok_or_else(invalid)?;
+                    hour = value;
+                    cursor = rest;
+                }
+                'M' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 2).ok_or_else(invalid)?;
+                    minute = value;
+                    cursor = rest;
+                }
+                'S' => {
+                    let (value, rest) = parse_fixed_digits(cursor, 2).ok_or_else(invalid)?;
+                    second = value;
+                    cursor = rest;
+                }
+                'z' => {
+                    if let Some(rest) = cursor.strip_prefix('Z') {
+                        offset = "Z".to_string();
+                        cursor = rest;
+                    } else {
+                        let sign = cursor.chars().next().ok_or_else(invalid)?;
+                        if sign != '+' && sign != '-' {
+                            return Err(invalid());
+                        }
+                        let (offset_hour, rest) =
+                            parse_fixed_digits(&cursor[1..], 2).ok_or_else(invalid)?;
+                        let rest = rest.strip_prefix(':').unwrap_or(rest);
+                        let (offset_minute, rest) =
+                            parse_fixed_digits(rest, 2).ok_or_else(invalid)?;
+                        offset = format!("{sign}{offset_hour:02}:{offset_minute:02}");
+                        cursor = rest;
+                    }
+                }
+                other => return Err(format!("unsupported pattern directive '%{other}'")),
+            }
+        } else {
+            cursor = cursor.strip_prefix(pat_char).ok_or_else(invalid)?;
+        }
+    }
+    if !cursor.is_empty() {
+        return Err(invalid());
+    }
+
+    let max_day = days_in_month(year, month).ok_or_else(invalid)?;
+    if month == 0 || month > 12 || day == 0 || day > max_day || hour > 23 || minute > 59 || second > 60 {
+        return Err(invalid());
+    }
+
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}{offset}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_picks_integer_float_boolean_from_type() {
+        assert_eq!(Conversion::resolve(Some("integer"), None, None), Conversion::Integer);
+        assert_eq!(Conversion::resolve(Some("number"), None, None), Conversion::Float);
+        assert_eq!(Conversion::resolve(Some("boolean"), None, None), Conversion::Boolean);
+        assert_eq!(Conversion::resolve(Some("string"), None, None), Conversion::String);
+        assert_eq!(Conversion::resolve(None, None, None), Conversion::String);
+        assert_eq!(Conversion::resolve(Some("object"), None, None), Conversion::Bytes);
+    }
+
+    #[test]
+    fn resolve_prefers_date_time_format_over_type() {
+        assert_eq!(
+            Conversion::resolve(Some("string"), Some("date-time"), None),
+            Conversion::Timestamp
+        );
+        assert_eq!(
+            Conversion::resolve(Some("string"), Some("date-time"), Some("%Y/%m/%d %H:%M:%S")),
+            Conversion::TimestampFmt("%Y/%m/%d %H:%M:%S".to_string())
+        );
+        assert_eq!(
+            Conversion::resolve(Some("string"), Some("date-time"), Some("%Y/%m/%d %H:%M:%S%z")),
+            Conversion::TimestampTZFmt("%Y/%m/%d %H:%M:%S%z".to_string())
+        );
+    }
+
+    #[test]
+    fn passthrough_conversions_need_no_coercion() {
+        assert_eq!(coerce("hello", &Conversion::String), Ok(None));
+        assert_eq!(coerce("hello", &Conversion::Bytes), Ok(None));
+        assert!(Conversion::String.is_passthrough());
+        assert!(!Conversion::Integer.is_passthrough());
+    }
+
+    #[test]
+    fn integer_round_trips_and_rejects_non_integers() {
+        assert_eq!(coerce("42", &Conversion::Integer), Ok(Some(CoercedValue::Integer(42))));
+        assert_eq!(coerce("-7", &Conversion::Integer), Ok(Some(CoercedValue::Integer(-7))));
+        assert!(coerce("42.5", &Conversion::Integer).is_err());
+        assert!(coerce("nope", &Conversion::Integer).is_err());
+    }
+
+    #[test]
+    fn float_round_trips_and_rejects_non_numbers() {
+        assert_eq!(coerce("3.5", &Conversion::Float), Ok(Some(CoercedValue::Float(3.5))));
+        assert_eq!(coerce("-2", &Conversion::Float), Ok(Some(CoercedValue::Float(-2.0))));
+        assert_eq!(coerce("1e3", &Conversion::Float), Ok(Some(CoercedValue::Float(1000.0))));
+        assert!(coerce("nan", &Conversion::Float).is_err());
+        assert!(coerce("1.2.3", &Conversion::Float).is_err());
+    }
+
+    #[test]
+    fn boolean_only_accepts_literal_true_or_false() {
+        assert_eq!(coerce("true", &Conversion::Boolean), Ok(Some(CoercedValue::Boolean(true))));
+        assert_eq!(coerce("false", &Conversion::Boolean), Ok(Some(CoercedValue::Boolean(false))));
+        assert!(coerce("True", &Conversion::Boolean).is_err());
+        assert!(coerce("1", &Conversion::Boolean).is_err());
+    }
+
+    #[test]
+    fn timestamp_normalizes_punctuation_and_keeps_offset() {
+        assert_eq!(
+            coerce("2024-01-02T03:04:05Z", &Conversion::Timestamp),
+            Ok(Some(CoercedValue::Timestamp("2024-01-02T03:04:05Z".to_string())))
+        );
+        assert_eq!(
+            coerce("2024-01-02t03:04:05.5+02:00", &Conversion::Timestamp),
+            Ok(Some(CoercedValue::Timestamp(
+                "2024-01-02T03:04:05.5+02:00".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn timestamp_rejects_invalid_calendar_dates() {
+        assert!(coerce("2023-02-29T00:00:00Z", &Conversion::Timestamp).is_err());
+        assert!(coerce("2024-02-30T00:00:00Z", &Conversion::Timestamp).is_err());
+        assert!(coerce("2024-13-01T00:00:00Z", &Conversion::Timestamp).is_err());
+        assert!(coerce("not-a-timestamp", &Conversion::Timestamp).is_err());
+    }
+
+    #[test]
+    fn timestamp_fmt_parses_custom_pattern_without_offset() {
+        let conversion = Conversion::TimestampFmt("%Y/%m/%d %H:%M:%S".to_string());
+        assert_eq!(
+            coerce("2024/01/02 03:04:05", &conversion),
+            Ok(Some(CoercedValue::Timestamp("2024-01-02T03:04:05Z".to_string())))
+        );
+        assert!(coerce("not a date", &conversion).is_err());
+    }
+
+    #[test]
+    fn timestamp_tz_fmt_parses_custom_pattern_with_offset() {
+        let conversion = Conversion::TimestampTZFmt("%Y/%m/%d %H:%M:%S%z".to_string());
+        assert_eq!(
+            coerce("2024/01/02 03:04:05+05:30", &conversion),
+            Ok(Some(CoercedValue::Timestamp(
+                "2024-01-02T03:04:05+05:30".to_string()
+            )))
+        );
+        assert_eq!(
+            coerce("2024/01/02 03:04:05Z", &conversion),
+            Ok(Some(CoercedValue::Timestamp("2024-01-02T03:04:05Z".to_string())))
+        );
+    }
+}
@@ -0,0 +1,236 @@
+//! A standalone query for which of an object instance's properties a
+//! schema's `properties`/`patternProperties`/`additionalProperties`/
+//! `allOf`/`anyOf`/`oneOf`/`if`-`then`-`else`/`dependentSchemas` keywords
+//! would consider evaluated, in the same sense `unevaluatedProperties`
+//! gives that term.
+//!
+//! This mirrors `DefaultPropertiesFilter::mark_evaluated_properties` (see
+//! `keywords::unevaluated_properties`), but compiles each subschema on its
+//! own with [`crate::validator_for`] instead of threading a shared
+//! `compiler::Context` through a purpose-built filter tree — `compiler::
+//! Context` and `SchemaNode` aren't part of this checkout. Unlike that
+//! filter, this query does not follow `$ref`/`$dynamicRef` when marking: a
+//! property that only a referenced schema evaluates is reported as
+//! unevaluated. Each validity check compiles its subschema as a standalone
+//! root, so a reference inside it resolves against that subschema, not
+//! against the outer `schema`.
+//!
+//! TODO: this recompiles a `Validator` per subschema on every call instead
+//! of reusing annotations collected during one validation pass — the same
+//! gap `PropertiesFilter` itself has (see its TODO). Fine for an ad hoc
+//! query; wasteful if called in a hot loop over many instances.
+
+use ahash::AHashSet;
+use fancy_regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::{ecma, validator_for};
+
+/// The partition of an object instance's property names into those a
+/// schema's applicator keywords evaluated and those left unevaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct EvaluatedProperties<'i> {
+    pub(crate) evaluated: Vec<&'i String>,
+    pub(crate) unevaluated: Vec<&'i String>,
+}
+
+/// Computes which properties of `instance` are evaluated by `schema`.
+///
+/// Returns `None` if `instance` isn't an object — the partition is only
+/// meaningful for object instances, the same restriction
+/// `unevaluatedProperties` itself has.
+pub(crate) fn evaluated_properties<'i>(
+    schema: &Value,
+    instance: &'i Value,
+) -> Option<EvaluatedProperties<'i>> {
+    let object = instance.as_object()?;
+
+    let mut evaluated = AHashSet::new();
+    mark_evaluated(schema, instance, &mut evaluated);
+
+    let mut result = EvaluatedProperties {
+        evaluated: Vec::new(),
+        unevaluated: Vec::new(),
+    };
+    for property in object.keys() {
+        if evaluated.contains(property) {
+            result.evaluated.push(property);
+        } else {
+            result.unevaluated.push(property);
+        }
+    }
+    Some(result)
+}
+
+fn is_valid(schema: &Value, instance: &Value) -> bool {
+    validator_for(schema).is_ok_and(|validator| validator.is_valid(instance))
+}
+
+fn mark_evaluated<'i>(schema: &Value, instance: &'i Value, evaluated: &mut AHashSet<&'i String>) {
+    let Some(parent) = schema.as_object() else {
+        return;
+    };
+
+    if let Value::Object(object) = instance {
+        mark_evaluated_in_object(parent, object, evaluated);
+
+        if let Some(Value::Object(dependent)) = parent.get("dependentSchemas") {
+            for (property, subschema) in dependent {
+                if object.contains_key(property) {
+                    mark_evaluated(subschema, instance, evaluated);
+                }
+            }
+        }
+    }
+
+    if let Some(Value::Array(subschemas)) = parent.get("allOf") {
+        if subschemas.iter().all(|s| is_valid(s, instance)) {
+            for subschema in subschemas {
+                mark_evaluated(subschema, instance, evaluated);
+            }
+        }
+    }
+
+    if let Some(Value::Array(subschemas)) = parent.get("anyOf") {
+        if subschemas.iter().any(|s| is_valid(s, instance)) {
+            for subschema in subschemas {
+                mark_evaluated(subschema, instance, evaluated);
+            }
+        }
+    }
+
+    if let Some(Value::Array(subschemas)) = parent.get("oneOf") {
+        let matches: Vec<bool> = subschemas.iter().map(|s| is_valid(s, instance)).collect();
+        if matches.iter().filter(|matched| **matched).count() == 1 {
+            for (subschema, matched) in subschemas.iter().zip(matches) {
+                if matched {
+                    mark_evaluated(subschema, instance, evaluated);
+                }
+            }
+        }
+    }
+
+    if let Some(if_schema) = parent.get("if") {
+        if is_valid(if_schema, instance) {
+            mark_evaluated(if_schema, instance, evaluated);
+            if let Some(then_schema) = parent.get("then") {
+                mark_evaluated(then_schema, instance, evaluated);
+            }
+        } else if let Some(else_schema) = parent.get("else") {
+            mark_evaluated(else_schema, instance, evaluated);
+        }
+    }
+}
+
+fn mark_evaluated_in_object<'i>(
+    parent: &Map<String, Value>,
+    object: &'i Map<String, Value>,
+    evaluated: &mut AHashSet<&'i String>,
+) {
+    if let Some(Value::Object(properties)) = parent.get("properties") {
+        for (property, value) in object {
+            if let Some(subschema) = properties.get(property) {
+                if is_valid(subschema, value) {
+                    evaluated.insert(property);
+                }
+            }
+        }
+    }
+
+    if let Some(Value::Object(patterns)) = parent.get("patternProperties") {
+        for (property, value) in object {
+            for (pattern, subschema) in patterns {
+                if pattern_matches(pattern, property) && is_valid(subschema, value) {
+                    evaluated.insert(property);
+                }
+            }
+        }
+    }
+
+    if let Some(additional) = parent.get("additionalProperties") {
+        for (property, value) in object {
+            if is_valid(additional, value) {
+                evaluated.insert(property);
+            }
+        }
+    }
+
+    if let Some(unevaluated) = parent.get("unevaluatedProperties") {
+        for (property, value) in object {
+            if is_valid(unevaluated, value) {
+                evaluated.insert(property);
+            }
+        }
+    }
+}
+
+fn pattern_matches(pattern: &str, property: &str) -> bool {
+    let Ok(rust_pattern) = ecma::to_rust_regex(pattern) else {
+        return false;
+    };
+    let Ok(regex) = Regex::new(&rust_pattern) else {
+        return false;
+    };
+    regex.is_match(property).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::evaluated_properties;
+    use serde_json::json;
+
+    #[test]
+    fn properties_keyword_marks_matching_keys_evaluated() {
+        let schema = json!({
+            "properties": {"name": {"type": "string"}}
+        });
+        let instance = json!({"name": "alice", "extra": 1});
+        let result = evaluated_properties(&schema, &instance).unwrap();
+        assert_eq!(result.evaluated, vec!["name"]);
+        assert_eq!(result.unevaluated, vec!["extra"]);
+    }
+
+    #[test]
+    fn pattern_properties_marks_matching_keys_evaluated() {
+        let schema = json!({
+            "patternProperties": {"^x-": {"type": "string"}}
+        });
+        let instance = json!({"x-custom": "value", "other": 1});
+        let result = evaluated_properties(&schema, &instance).unwrap();
+        assert_eq!(result.evaluated, vec!["x-custom"]);
+        assert_eq!(result.unevaluated, vec!["other"]);
+    }
+
+    #[test]
+    fn additional_properties_marks_remaining_keys_evaluated() {
+        let schema = json!({
+            "properties": {"name": {"type": "string"}},
+            "additionalProperties": {"type": "integer"}
+        });
+        let instance = json!({"name": "alice", "age": 30});
+        let result = evaluated_properties(&schema, &instance).unwrap();
+        assert!(result.evaluated.contains(&&"name".to_string()));
+        assert!(result.evaluated.contains(&&"age".to_string()));
+        assert!(result.unevaluated.is_empty());
+    }
+
+    #[test]
+    fn matching_one_of_branch_contributes_its_evaluated_properties() {
+        let schema = json!({
+            "oneOf": [
+                {"properties": {"name": {"type": "string"}}, "required": ["name"]},
+                {"properties": {"id": {"type": "integer"}}, "required": ["id"]},
+            ]
+        });
+        let instance = json!({"id": 1});
+        let result = evaluated_properties(&schema, &instance).unwrap();
+        assert_eq!(result.evaluated, vec!["id"]);
+    }
+
+    #[test]
+    fn non_object_instance_has_no_partition() {
+        let schema = json!({"properties": {"name": {"type": "string"}}});
+        let instance = json!("not an object");
+        assert!(evaluated_properties(&schema, &instance).is_none());
+    }
+}
@@ -0,0 +1,370 @@
+use crate::{
+    compiler,
+    error::{no_error, ErrorIterator, ValidationError},
+    keywords::{required, CompilationResult},
+    node::SchemaNode,
+    paths::{LazyLocation, Location, RefTracker},
+    properties::HASHMAP_THRESHOLD,
+    types::JsonType,
+    validator::{EvaluationResult, Validate, ValidationContext},
+};
+use ahash::AHashMap;
+use serde_json::{Map, Value};
+
+/// One `dependencies`/`dependentSchemas` entry: either a `required`-style
+/// name list (Draft 7's array form, also what `dependentRequired` always is
+/// -- see `keywords::dependent_required`) or a subschema applied to the
+/// whole instance (Draft 7's object form, also what `dependentSchemas`
+/// always is).
+enum Constraint {
+    Required(Box<dyn Validate>),
+    Schema(SchemaNode),
+}
+
+impl Constraint {
+    fn is_valid(&self, instance: &Value, ctx: &mut ValidationContext) -> bool {
+        match self {
+            Constraint::Required(validator) => validator.is_valid(instance, ctx),
+            Constraint::Schema(node) => node.is_valid(instance, ctx),
+        }
+    }
+
+    fn validate<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> Result<(), ValidationError<'i>> {
+        match self {
+            Constraint::Required(validator) => validator.validate(instance, location, tracker, ctx),
+            Constraint::Schema(node) => node.validate(instance, location, tracker, ctx),
+        }
+    }
+
+    fn iter_errors<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> ErrorIterator<'i> {
+        match self {
+            Constraint::Required(validator) => validator.iter_errors(instance, location, tracker, ctx),
+            Constraint::Schema(node) => node.iter_errors(instance, location, tracker, ctx),
+        }
+    }
+
+    fn evaluate(
+        &self,
+        instance: &Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> EvaluationResult {
+        match self {
+            Constraint::Required(validator) => validator.evaluate(instance, location, tracker, ctx),
+            Constraint::Schema(node) => node.evaluate_instance(instance, location, tracker, ctx),
+        }
+    }
+}
+
+pub(crate) struct SmallDependenciesValidator {
+    entries: Vec<(String, Constraint)>,
+}
+
+pub(crate) struct BigDependenciesValidator {
+    entries: AHashMap<String, Constraint>,
+}
+
+/// Compiles `map`'s entries into `Constraint`s under `ctx`, which must
+/// already be located at the keyword itself (`dependencies` or
+/// `dependentSchemas`). `mixed_form` is `true` for `dependencies` (Draft 7),
+/// where an array entry means `required` and anything else means a
+/// subschema, and `false` for `dependentSchemas`, where every entry is
+/// always a subschema.
+fn compile_entries<'a>(
+    ctx: &compiler::Context,
+    map: &'a Map<String, Value>,
+    mixed_form: bool,
+) -> Result<Vec<(String, Constraint)>, ValidationError<'a>> {
+    let mut entries = Vec::with_capacity(map.len());
+    for (trigger, subschema) in map {
+        let entry_ctx = ctx.new_at_location(trigger.as_str());
+        let constraint = if mixed_form && matches!(subschema, Value::Array(_)) {
+            match required::compile_with_path(subschema, entry_ctx.location().clone(), None) {
+                Some(Ok(validator)) => Constraint::Required(validator),
+                Some(Err(error)) => return Err(error),
+                None => continue,
+            }
+        } else {
+            Constraint::Schema(compiler::compile(
+                &entry_ctx,
+                entry_ctx.as_resource_ref(subschema),
+            )?)
+        };
+        entries.push((trigger.clone(), constraint));
+    }
+    Ok(entries)
+}
+
+impl SmallDependenciesValidator {
+    #[inline]
+    fn compile<'a>(
+        ctx: &compiler::Context,
+        map: &'a Map<String, Value>,
+        mixed_form: bool,
+    ) -> CompilationResult<'a> {
+        let entries = compile_entries(ctx, map, mixed_form)?;
+        Ok(Box::new(SmallDependenciesValidator { entries }))
+    }
+}
+
+impl BigDependenciesValidator {
+    #[inline]
+    fn compile<'a>(
+        ctx: &compiler::Context,
+        map: &'a Map<String, Value>,
+        mixed_form: bool,
+    ) -> CompilationResult<'a> {
+        let entries = compile_entries(ctx, map, mixed_form)?.into_iter().collect();
+        Ok(Box::new(BigDependenciesValidator { entries }))
+    }
+}
+
+impl Validate for SmallDependenciesValidator {
+    fn is_valid(&self, instance: &Value, ctx: &mut ValidationContext) -> bool {
+        if let Value::Object(item) = instance {
+            self.entries.iter().all(|(trigger, constraint)| {
+                !item.contains_key(trigger) || constraint.is_valid(instance, ctx)
+            })
+        } else {
+            true
+        }
+    }
+
+    fn validate<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> Result<(), ValidationError<'i>> {
+        if let Value::Object(item) = instance {
+            for (trigger, constraint) in &self.entries {
+                if item.contains_key(trigger) {
+                    constraint.validate(instance, location, tracker, ctx)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn iter_errors<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> ErrorIterator<'i> {
+        if let Value::Object(item) = instance {
+            let mut errors = Vec::new();
+            for (trigger, constraint) in &self.entries {
+                if item.contains_key(trigger) {
+                    errors.extend(constraint.iter_errors(instance, location, tracker, ctx));
+                }
+            }
+            if !errors.is_empty() {
+                return ErrorIterator::from_iterator(errors.into_iter());
+            }
+        }
+        no_error()
+    }
+
+    fn evaluate(
+        &self,
+        instance: &Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> EvaluationResult {
+        if let Value::Object(item) = instance {
+            let mut children = Vec::new();
+            for (trigger, constraint) in &self.entries {
+                if item.contains_key(trigger) {
+                    children.push(constraint.evaluate(instance, location, tracker, ctx));
+                }
+            }
+            EvaluationResult::from_children(children)
+        } else {
+            EvaluationResult::valid_empty()
+        }
+    }
+}
+
+impl Validate for BigDependenciesValidator {
+    fn is_valid(&self, instance: &Value, ctx: &mut ValidationContext) -> bool {
+        if let Value::Object(item) = instance {
+            item.keys().all(|key| match self.entries.get(key) {
+                Some(constraint) => constraint.is_valid(instance, ctx),
+                None => true,
+            })
+        } else {
+            true
+        }
+    }
+
+    fn validate<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> Result<(), ValidationError<'i>> {
+        if let Value::Object(item) = instance {
+            for key in item.keys() {
+                if let Some(constraint) = self.entries.get(key) {
+                    constraint.validate(instance, location, tracker, ctx)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn iter_errors<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> ErrorIterator<'i> {
+        if let Value::Object(item) = instance {
+            let mut errors = Vec::new();
+            for key in item.keys() {
+                if let Some(constraint) = self.entries.get(key) {
+                    errors.extend(constraint.iter_errors(instance, location, tracker, ctx));
+                }
+            }
+            if !errors.is_empty() {
+                return ErrorIterator::from_iterator(errors.into_iter());
+            }
+        }
+        no_error()
+    }
+
+    fn evaluate(
+        &self,
+        instance: &Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> EvaluationResult {
+        if let Value::Object(item) = instance {
+            let mut children = Vec::new();
+            for key in item.keys() {
+                if let Some(constraint) = self.entries.get(key) {
+                    children.push(constraint.evaluate(instance, location, tracker, ctx));
+                }
+            }
+            EvaluationResult::from_children(children)
+        } else {
+            EvaluationResult::valid_empty()
+        }
+    }
+}
+
+fn compile_keyword<'a>(
+    ctx: &compiler::Context,
+    schema: &'a Value,
+    keyword: &'static str,
+    mixed_form: bool,
+) -> Option<CompilationResult<'a>> {
+    let Value::Object(map) = schema else {
+        let location = ctx.location().join(keyword);
+        return Some(Err(ValidationError::single_type_error(
+            location.clone(),
+            location,
+            Location::new(),
+            schema,
+            JsonType::Object,
+        )));
+    };
+    let ctx = ctx.new_at_location(keyword);
+    if map.len() < HASHMAP_THRESHOLD {
+        Some(SmallDependenciesValidator::compile(&ctx, map, mixed_form))
+    } else {
+        Some(BigDependenciesValidator::compile(&ctx, map, mixed_form))
+    }
+}
+
+/// Draft 7 `dependencies`: each `(trigger, constraint)` entry gates either a
+/// `required`-style name list or a subschema behind the trigger property's
+/// presence. Superseded by the split `dependentRequired`/`dependentSchemas`
+/// keywords in 2019-09+, but Draft 7 schemas still use the combined form, so
+/// this keeps both readings live under a single keyword name.
+#[inline]
+pub(crate) fn compile<'a>(
+    ctx: &compiler::Context,
+    _parent: &'a Map<String, Value>,
+    schema: &'a Value,
+) -> Option<CompilationResult<'a>> {
+    compile_keyword(ctx, schema, "dependencies", true)
+}
+
+/// 2019-09+ `dependentSchemas`: `dependencies`'s object-only successor --
+/// every entry is a subschema validated against the whole instance when its
+/// trigger key is present. The array/`required`-style form was split out
+/// into its own `dependentRequired` keyword (see
+/// `keywords::dependent_required`), so entries here are always schemas.
+#[inline]
+pub(crate) fn compile_dependent_schemas<'a>(
+    ctx: &compiler::Context,
+    _parent: &'a Map<String, Value>,
+    schema: &'a Value,
+) -> Option<CompilationResult<'a>> {
+    compile_keyword(ctx, schema, "dependentSchemas", false)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_util;
+    use serde_json::{json, Value};
+    use test_case::test_case;
+
+    #[test_case(&json!({"dependencies": {"a": ["b"]}}), &json!({"a": 1}), "/dependencies/a")]
+    #[test_case(&json!({"dependencies": {"a": {"properties": {"b": {"type": "integer"}}}}}), &json!({"a": 1, "b": "x"}), "/dependencies/a/properties/b/type")]
+    fn location(schema: &Value, instance: &Value, expected: &str) {
+        tests_util::assert_schema_location(schema, instance, expected);
+    }
+
+    #[test_case(&json!({}), true)] // Trigger absent, entry vacuously satisfied
+    #[test_case(&json!({"a": 1}), false)] // Trigger present, "b" missing
+    #[test_case(&json!({"a": 1, "b": 2}), true)]
+    fn required_form(instance: &Value, expected: bool) {
+        let schema = json!({"dependencies": {"a": ["b"]}});
+        let validator = crate::validator_for(&schema).unwrap();
+        assert_eq!(validator.is_valid(instance), expected);
+    }
+
+    #[test_case(&json!({}), true)] // Trigger absent, subschema never applied
+    #[test_case(&json!({"a": 1, "b": "x"}), true)]
+    #[test_case(&json!({"a": 1, "b": 2}), false)] // Trigger present, "b" must be a string
+    fn schema_form(instance: &Value, expected: bool) {
+        let schema = json!({
+            "dependencies": {"a": {"properties": {"b": {"type": "string"}}}}
+        });
+        let validator = crate::validator_for(&schema).unwrap();
+        assert_eq!(validator.is_valid(instance), expected);
+    }
+
+    #[test_case(&json!({}), true)]
+    #[test_case(&json!({"a": 1, "b": 2}), false)]
+    #[test_case(&json!({"a": 1, "b": "x"}), true)]
+    fn dependent_schemas(instance: &Value, expected: bool) {
+        let schema = json!({
+            "dependentSchemas": {"a": {"properties": {"b": {"type": "string"}}}}
+        });
+        let validator = crate::validator_for(&schema).unwrap();
+        assert_eq!(validator.is_valid(instance), expected);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::{
+    compiler,
+    error::{no_error, ErrorIterator, ValidationError},
+    keywords::{required, CompilationResult},
+    paths::{LazyLocation, Location, RefTracker},
+    types::JsonType,
+    validator::{Validate, ValidationContext},
+};
+use serde_json::{Map, Value};
+
+/// Draft 2019-09+ `dependentRequired`: each `(trigger, [names])` entry gates a
+/// `required`-style check behind the trigger property's presence, so the
+/// listed names only become mandatory once `trigger` shows up in the
+/// instance. Built directly on top of `required::compile_with_path`, so an
+/// entry of length 1/2/3 gets the same `SingleItemRequiredValidator`/
+/// `Required2Validator`/`Required3Validator` fast paths `required` itself
+/// uses, without pulling in subschema compilation the way `dependentSchemas`
+/// would.
+pub(crate) struct DependentRequiredValidator {
+    entries: Vec<(String, Box<dyn Validate>)>,
+}
+
+impl DependentRequiredValidator {
+    #[inline]
+    pub(crate) fn compile<'a>(
+        ctx: &compiler::Context,
+        schema: &'a Value,
+    ) -> Option<CompilationResult<'a>> {
+        let Value::Object(map) = schema else {
+            let location = ctx.location().join("dependentRequired");
+            return Some(Err(ValidationError::single_type_error(
+                location.clone(),
+                location,
+                Location::new(),
+                schema,
+                JsonType::Object,
+            )));
+        };
+        let mut entries = Vec::with_capacity(map.len());
+        for (trigger, names) in map {
+            let location = ctx
+                .location()
+                .join("dependentRequired")
+                .join(trigger.as_str());
+            // `dependentRequired` has no `x-errorMessage` annotation contract
+            // of its own, so every entry compiles with the default
+            // `ValidationError::required` message.
+            match required::compile_with_path(names, location, None) {
+                Some(Ok(validator)) => entries.push((trigger.clone(), validator)),
+                Some(Err(error)) => return Some(Err(error)),
+                None => {}
+            }
+        }
+        Some(Ok(Box::new(DependentRequiredValidator { entries })))
+    }
+}
+
+impl Validate for DependentRequiredValidator {
+    fn is_valid(&self, instance: &Value, ctx: &mut ValidationContext) -> bool {
+        if let Value::Object(item) = instance {
+            self.entries.iter().all(|(trigger, validator)| {
+                !item.contains_key(trigger) || validator.is_valid(instance, ctx)
+            })
+        } else {
+            true
+        }
+    }
+
+    fn validate<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> Result<(), ValidationError<'i>> {
+        if let Value::Object(item) = instance {
+            for (trigger, validator) in &self.entries {
+                if item.contains_key(trigger) {
+                    validator.validate(instance, location, tracker, ctx)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn iter_errors<'i>(
+        &self,
+        instance: &'i Value,
+        location: &LazyLocation,
+        tracker: Option<&RefTracker>,
+        ctx: &mut ValidationContext,
+    ) -> ErrorIterator<'i> {
+        if let Value::Object(item) = instance {
+            let mut errors = Vec::new();
+            for (trigger, validator) in &self.entries {
+                if item.contains_key(trigger) {
+                    errors.extend(validator.iter_errors(instance, location, tracker, ctx));
+                }
+            }
+            if !errors.is_empty() {
+                return ErrorIterator::from_iterator(errors.into_iter());
+            }
+        }
+        no_error()
+    }
+}
+
+#[inline]
+pub(crate) fn compile<'a>(
+    ctx: &compiler::Context,
+    _parent: &'a Map<String, Value>,
+    schema: &'a Value,
+) -> Option<CompilationResult<'a>> {
+    DependentRequiredValidator::compile(ctx, schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests_util;
+    use serde_json::{json, Value};
+    use test_case::test_case;
+
+    #[test_case(&json!({"dependentRequired": {"a": ["b"]}}), &json!({"a": 1}), "/dependentRequired/a")]
+    #[test_case(&json!({"dependentRequired": {"a": ["b", "c"]}}), &json!({"a": 1}), "/dependentRequired/a")]
+    fn location(schema: &Value, instance: &Value, expected: &str) {
+        tests_util::assert_schema_location(schema, instance, expected);
+    }
+
+    #[test_case(&json!({}), true)] // Trigger absent, entry vacuously satisfied
+    #[test_case(&json!({"a": 1}), false)] // Trigger present, "b" missing
+    #[test_case(&json!({"a": 1, "b": 2}), true)]
+    #[test_case(&json!({"b": 2}), true)] // No trigger, "b" alone doesn't matter
+    fn single_name(instance: &Value, expected: bool) {
+        let schema = json!({"dependentRequired": {"a": ["b"]}});
+        let validator = crate::validator_for(&schema).unwrap();
+        assert_eq!(validator.is_valid(instance), expected);
+    }
+
+    #[test_case(&json!({}), true)]
+    #[test_case(&json!({"a": 1}), false)]
+    #[test_case(&json!({"a": 1, "b": 2}), false)]
+    #[test_case(&json!({"a": 1, "b": 2, "c": 3}), true)]
+    fn multiple_names(instance: &Value, expected: bool) {
+        let schema = json!({"dependentRequired": {"a": ["b", "c"]}});
+        let validator = crate::validator_for(&schema).unwrap();
+        assert_eq!(validator.is_valid(instance), expected);
+    }
+
+    #[test]
+    fn iter_errors_aggregates_missing_names() {
+        let schema = json!({"dependentRequired": {"a": ["b", "c"]}});
+        let validator = crate::validator_for(&schema).unwrap();
+
+        // "b" and "c" compile through `required::compile_with_path` into a
+        // `Required2Validator`, which (since chunk14-1) aggregates every
+        // missing name into a single `required` error rather than reporting
+        // one per name.
+        let instance = json!({"a": 1});
+        let errors: Vec<_> = validator.iter_errors(&instance).collect();
+        assert_eq!(errors.len(), 1);
+
+        let instance = json!({});
+        let errors: Vec<_> = validator.iter_errors(&instance).collect();
+        assert!(errors.is_empty());
+    }
+}
@@ -96,6 +96,24 @@ impl Validate for ItemsArrayValidator {
     }
 }
 
+// STATUS: BLOCKED, needs escalation to whoever owns this backlog. This
+// request asked for a rayon-backed parallel fan-out for large-array items
+// validation; the prior commit here only recorded why it's hard, which
+// isn't the same as delivering it. It stays blocked on `ValidationOptions`'s
+// builder internals and `ValidationContext`'s real ownership model, neither
+// present in this checkout.
+// TODO(parallel items): for large homogeneous arrays, `is_valid`/
+// `iter_errors`/`evaluate` below walk `items` strictly sequentially, which
+// is a bottleneck at scale. An opt-in `parallel_items(n)` fan-out (split the
+// slice into chunks, validate across a worker pool, merge per-index errors
+// back in order) needs two things this checkout doesn't have visibility
+// into: a way to read that threshold back out of `ValidationOptions` at
+// validate time (its builder methods aren't part of this checkout), and a
+// way to give each worker its own `&mut ValidationContext` — cloning it
+// safely depends on what state it actually owns (dynamic scope, annotation
+// accumulators), which lives in `validator.rs`, also not part of this
+// checkout. Guessing at either risks inventing an API that doesn't match
+// the real one, so this stays sequential for now.
 pub(crate) struct ItemsObjectValidator {
     node: SchemaNode,
 }
@@ -133,6 +151,9 @@ impl Validate for ItemsObjectValidator {
         Ok(())
     }
 
+    // Lazy: yields each child error on demand instead of collecting every
+    // item's errors up front, so a caller that `.take(n)`s or short-circuits
+    // doesn't pay for validating the rest of a large array.
     fn iter_errors<'i>(
         &self,
         instance: &'i Value,
@@ -140,18 +161,22 @@ impl Validate for ItemsObjectValidator {
         tracker: Option<&RefTracker>,
         ctx: &mut ValidationContext,
     ) -> ErrorIterator<'i> {
-        if let Value::Array(items) = instance {
-            let mut errors = Vec::new();
-            for (idx, item) in items.iter().enumerate() {
-                errors.extend(
-                    self.node
-                        .iter_errors(item, &location.push(idx), tracker, ctx),
-                );
+        let Value::Array(items) = instance else {
+            return no_error();
+        };
+        let node = &self.node;
+        let mut indices = items.iter().enumerate();
+        let mut current: Option<ErrorIterator<'i>> = None;
+        ErrorIterator::from_iterator(std::iter::from_fn(move || loop {
+            if let Some(pending) = current.as_mut() {
+                if let Some(error) = pending.next() {
+                    return Some(error);
+                }
+                current = None;
             }
-            ErrorIterator::from_iterator(errors.into_iter())
-        } else {
-            no_error()
-        }
+            let (idx, item) = indices.next()?;
+            current = Some(node.iter_errors(item, &location.push(idx), tracker, &mut *ctx));
+        }))
     }
 
     fn evaluate(
@@ -228,6 +253,7 @@ impl Validate for ItemsObjectSkipPrefixValidator {
         Ok(())
     }
 
+    // Lazy for the same reason as `ItemsObjectValidator::iter_errors`.
     fn iter_errors<'i>(
         &self,
         instance: &'i Value,
@@ -235,20 +261,28 @@ impl Validate for ItemsObjectSkipPrefixValidator {
         tracker: Option<&RefTracker>,
         ctx: &mut ValidationContext,
     ) -> ErrorIterator<'i> {
-        if let Value::Array(items) = instance {
-            let mut errors = Vec::new();
-            for (idx, item) in items.iter().skip(self.skip_prefix).enumerate() {
-                errors.extend(self.node.iter_errors(
-                    item,
-                    &location.push(idx + self.skip_prefix),
-                    tracker,
-                    ctx,
-                ));
+        let Value::Array(items) = instance else {
+            return no_error();
+        };
+        let node = &self.node;
+        let skip_prefix = self.skip_prefix;
+        let mut indices = items.iter().skip(skip_prefix).enumerate();
+        let mut current: Option<ErrorIterator<'i>> = None;
+        ErrorIterator::from_iterator(std::iter::from_fn(move || loop {
+            if let Some(pending) = current.as_mut() {
+                if let Some(error) = pending.next() {
+                    return Some(error);
+                }
+                current = None;
             }
-            ErrorIterator::from_iterator(errors.into_iter())
-        } else {
-            no_error()
-        }
+            let (idx, item) = indices.next()?;
+            current = Some(node.iter_errors(
+                item,
+                &location.push(idx + skip_prefix),
+                tracker,
+                &mut *ctx,
+            ));
+        }))
     }
 
     fn evaluate(
@@ -278,6 +312,13 @@ impl Validate for ItemsObjectSkipPrefixValidator {
 
 // Specialized validators for common simple item schemas.
 // These avoid dynamic dispatch overhead by inlining the type check.
+//
+// Their `iter_errors` below still collect eagerly, unlike
+// `ItemsObjectValidator`/`ItemsObjectSkipPrefixValidator` above: each item
+// can only ever produce at most one error here (a single type check), so
+// there's no nested-error fan-out to materialize up front — the eager `Vec`
+// this type of validator builds is already bounded by the array length, not
+// by how deep a recursively-compiled `SchemaNode` happens to be.
 
 pub(crate) struct ItemsNumberTypeValidator {
     location: Location,
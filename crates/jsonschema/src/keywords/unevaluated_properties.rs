@@ -16,6 +16,27 @@ use crate::{
 
 use super::CompilationResult;
 
+// STATUS: BLOCKED, needs escalation to whoever owns this backlog. This
+// request asked for `unevaluatedProperties` to reuse sibling keywords'
+// already-computed annotations instead of recompiling/re-evaluating their
+// subschemas. That reuse has to live in the primary `SchemaNode`/compiler
+// pass described below, which this checkout doesn't contain, so it can't be
+// delivered from this file without inventing the shape of an API this
+// checkout can't verify against.
+// TODO(annotation reuse): `mark_evaluated_properties` below re-derives which
+// properties an applicator keyword covers by re-running `is_valid` on each
+// sibling subschema (see `Draft2019PropertiesFilter`/`DefaultPropertiesFilter`),
+// which is why `PropertiesFilter` exists as a second, separately-compiled copy
+// of `properties`/`patternProperties`/`additionalProperties`/`allOf`/`anyOf`/
+// `oneOf`/`if`-`then`-`else`/`$ref` alongside the real `SchemaNode` tree. The
+// spec-accurate fix is for the primary `validate`/`is_valid` pass on
+// `SchemaNode` (crate::node, not part of this checkout) to thread a mutable
+// "evaluated property names" scratch set through `compiler::compile`'s
+// applicator keywords as they run, so this validator can read the union
+// collected from the one real pass instead of owning a parallel one. That
+// requires changes to `SchemaNode`'s `Validate` impl and the compiler's
+// recursive descent, neither of which is present in this checkout, so it
+// can't be done from this file alone without guessing at their shape.
 pub(crate) trait PropertiesFilter: ThreadBound + Sized + 'static {
     fn new<'a>(
         ctx: &'a compiler::Context<'_>,
@@ -777,6 +798,22 @@ impl<F: PropertiesFilter> CombinatorFilter<F> {
     }
 }
 
+// STATUS: BLOCKED, needs escalation to whoever owns this backlog. This
+// request asked for evaluated-property annotations to be exposed through a
+// structured output API. The provenance is computed right here (which
+// if/then/else branch ran, which oneOf branch matched) but there is no
+// `Evaluation`-shaped type in this checkout to carry it out through, and
+// `Evaluation` is defined outside this checkout, so this can't be wired up
+// without guessing at that type's shape.
+// TODO(annotation provenance): `ConditionalFilter::mark_evaluated_properties`
+// below already knows which side of `if`/`then`/`else` ran, and the `one_of`
+// arm of each `PropertiesFilter::mark_evaluated_properties` impl already knows
+// which `oneOf` branch matched — both are computed then discarded, since only
+// the union of evaluated property names is kept. A structured output API
+// exposing that provenance (building on the single-pass redesign noted where
+// `PropertiesFilter` is defined above) would need an `Evaluation`-shaped type
+// to return it through; that type isn't part of this checkout, so there's
+// nowhere to surface this data publicly yet.
 struct ConditionalFilter<F> {
     condition: SchemaNode,
     if_: F,
@@ -947,6 +984,21 @@ mod tests {
         );
     }
 
+    // STATUS: BLOCKED, needs escalation to whoever owns this backlog. This
+    // request asked for single-pass annotation collection to replace
+    // re-validation for `unevaluatedProperties`; this test is the sharpest
+    // case of the same compiler/SchemaNode-level blocker noted above
+    // `PropertiesFilter`'s definition, since recursion multiplies the
+    // re-validation cost instead of just paying it once.
+    // TODO(annotation reuse): this is the worst case for the re-validation
+    // cost described in the `PropertiesFilter` TODO above — each recursion
+    // through `1_1`/`1_2` re-runs `is_valid` on the referenced subschema to
+    // recover its evaluated-property set, so the cost compounds once per
+    // level of nesting in the instance rather than being paid once per
+    // validation pass. A single-pass annotation model (properties/items/
+    // prefix_items already produce annotations this way, see that TODO)
+    // would let each `$ref` application read back an annotation already
+    // computed for that node instead of re-deriving it by revalidating.
     #[test]
     fn test_unevaluated_properties_with_recursion() {
         // See GH-420
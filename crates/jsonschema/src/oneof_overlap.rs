@@ -0,0 +1,323 @@
+//! Compile-time disjointness analysis for `oneOf`.
+//!
+//! `oneOf` requires an instance to match *exactly one* branch. Schema
+//! authors often reach for it expecting the branches to behave like a
+//! discriminated union, but nothing stops two branches from describing
+//! overlapping instances — in which case some valid-looking instance
+//! silently fails the whole schema by matching two branches at once.
+//!
+//! [`oneof_branch_overlaps`] walks a schema document looking for `oneOf`
+//! keywords and, for each pair of branches, tries to *prove* they're
+//! disjoint from a cheap structural signature (allowed `type`s, `const`/
+//! `enum` values, `required` property names, and per-property constraints
+//! for properties required by both branches). Disjointness in general is
+//! undecidable from a structural summary alone, so this only ever proves
+//! the positive case; a pair it can't prove disjoint is reported as a
+//! potential overlap, not a confirmed one — the schema may still be fine.
+//!
+//! TODO: wiring this up as an opt-in check on `ValidationOptions` (so it
+//! runs automatically at `build()` time) needs the options builder, which
+//! isn't part of this checkout; for now this is only reachable by calling
+//! `oneof_branch_overlaps` directly.
+//!
+//! The walk treats every object key as a potential nested schema location,
+//! which means a `oneOf` appearing inside schema *data* (e.g. inside a
+//! `const` or `examples` value, rather than as an actual keyword) would
+//! also be visited. That's a known over-approximation: the extra pointer
+//! it would report is on data that was never a constraint in the first
+//! place, so it can only add noise, never hide a real overlap.
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+/// A pair of `oneOf` branches that could not be proven disjoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OneOfOverlap {
+    /// JSON Pointer to the `oneOf` array containing both branches.
+    pub(crate) pointer: String,
+    /// Index of the first branch.
+    pub(crate) left: usize,
+    /// Index of the second branch.
+    pub(crate) right: usize,
+}
+
+/// Finds every `oneOf` array in `schema` and reports branch pairs that
+/// cannot be proven disjoint by structural signature comparison.
+pub(crate) fn oneof_branch_overlaps(schema: &Value) -> Vec<OneOfOverlap> {
+    let mut overlaps = Vec::new();
+    walk(schema, "", &mut overlaps);
+    overlaps
+}
+
+fn walk(value: &Value, pointer: &str, overlaps: &mut Vec<OneOfOverlap>) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Array(branches)) = map.get("oneOf") {
+                let oneof_pointer = format!("{pointer}/oneOf");
+                for left in 0..branches.len() {
+                    for right in (left + 1)..branches.len() {
+                        if !provably_disjoint(&branches[left], &branches[right]) {
+                            overlaps.push(OneOfOverlap {
+                                pointer: oneof_pointer.clone(),
+                                left,
+                                right,
+                            });
+                        }
+                    }
+                }
+            }
+            for (key, nested) in map {
+                walk(nested, &format!("{pointer}/{}", escape_pointer_segment(key)), overlaps);
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                walk(item, &format!("{pointer}/{index}"), overlaps);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// A cheap structural summary of a subschema used to judge disjointness.
+#[derive(Debug, Default)]
+struct Signature {
+    /// `true` if this is the `false` schema, which no instance ever matches.
+    unsatisfiable: bool,
+    /// Allowed `type` names, or `None` if `type` is unconstrained.
+    types: Option<BTreeSet<String>>,
+    /// Allowed values from `const`/`enum`, or `None` if unconstrained.
+    values: Option<Vec<Value>>,
+    /// Property names listed under `required`.
+    required: BTreeSet<String>,
+    /// Property names accepted by `properties` when `additionalProperties`
+    /// is `false` and there is no `patternProperties`, meaning no other
+    /// property name is ever valid here.
+    closed_properties: Option<BTreeSet<String>>,
+    /// Per-property signatures, for properties declared under `properties`.
+    properties: std::collections::BTreeMap<String, Signature>,
+}
+
+fn signature_of(schema: &Value) -> Signature {
+    let Value::Object(map) = schema else {
+        return Signature {
+            unsatisfiable: matches!(schema, Value::Bool(false)),
+            ..Signature::default()
+        };
+    };
+
+    let mut signature = Signature::default();
+
+    if let Some(type_value) = map.get("type") {
+        signature.types = Some(type_set(type_value));
+    }
+
+    if let Some(const_value) = map.get("const") {
+        signature.values = Some(vec![const_value.clone()]);
+    } else if let Some(Value::Array(options)) = map.get("enum") {
+        signature.values = Some(options.clone());
+    }
+
+    if let Some(Value::Array(required)) = map.get("required") {
+        signature.required = required
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect();
+    }
+
+    if let Some(Value::Object(properties)) = map.get("properties") {
+        for (name, subschema) in properties {
+            signature
+                .properties
+                .insert(name.clone(), signature_of(subschema));
+        }
+    }
+
+    if matches!(map.get("additionalProperties"), Some(Value::Bool(false)))
+        && !map.contains_key("patternProperties")
+    {
+        signature.closed_properties = Some(signature.properties.keys().cloned().collect());
+    }
+
+    signature
+}
+
+fn type_set(type_value: &Value) -> BTreeSet<String> {
+    match type_value {
+        Value::String(name) => BTreeSet::from([name.clone()]),
+        Value::Array(names) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+fn provably_disjoint(left: &Value, right: &Value) -> bool {
+    disjoint_signatures(&signature_of(left), &signature_of(right))
+}
+
+fn disjoint_signatures(left: &Signature, right: &Signature) -> bool {
+    if left.unsatisfiable || right.unsatisfiable {
+        return true;
+    }
+
+    if let (Some(left_types), Some(right_types)) = (&left.types, &right.types) {
+        // Every integer is also a number, so those two names overlap.
+        let integer_in_number = |a: &BTreeSet<String>, b: &BTreeSet<String>| {
+            a.contains("integer") && b.contains("number")
+        };
+        if left_types.is_disjoint(right_types)
+            && !integer_in_number(left_types, right_types)
+            && !integer_in_number(right_types, left_types)
+        {
+            return true;
+        }
+    }
+
+    if let (Some(left_values), Some(right_values)) = (&left.values, &right.values) {
+        if !left_values.iter().any(|value| right_values.contains(value)) {
+            return true;
+        }
+    }
+
+    if required_excluded_by_closed_properties(left, right)
+        || required_excluded_by_closed_properties(right, left)
+    {
+        return true;
+    }
+
+    for (name, left_property) in &left.properties {
+        if !(left.required.contains(name) && right.required.contains(name)) {
+            continue;
+        }
+        if let Some(right_property) = right.properties.get(name) {
+            if disjoint_signatures(left_property, right_property) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// `true` if `required_side` requires a property that `closed_side` can
+/// never accept, because `closed_side` declares a closed property set
+/// (via `additionalProperties: false`) that doesn't include it.
+fn required_excluded_by_closed_properties(required_side: &Signature, closed_side: &Signature) -> bool {
+    let Some(closed) = &closed_side.closed_properties else {
+        return false;
+    };
+    required_side
+        .required
+        .iter()
+        .any(|name| !closed.contains(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::oneof_branch_overlaps;
+    use serde_json::json;
+
+    #[test]
+    fn disjoint_types_report_no_overlap() {
+        let schema = json!({
+            "oneOf": [
+                {"type": "string"},
+                {"type": "integer"},
+            ]
+        });
+        assert!(oneof_branch_overlaps(&schema).is_empty());
+    }
+
+    #[test]
+    fn overlapping_types_are_reported() {
+        let schema = json!({
+            "oneOf": [
+                {"type": "string", "minLength": 1},
+                {"type": "string", "maxLength": 5},
+            ]
+        });
+        let overlaps = oneof_branch_overlaps(&schema);
+        assert_eq!(overlaps.len(), 1);
+        assert_eq!(overlaps[0].pointer, "/oneOf");
+        assert_eq!((overlaps[0].left, overlaps[0].right), (0, 1));
+    }
+
+    #[test]
+    fn disjoint_const_values_report_no_overlap() {
+        let schema = json!({
+            "oneOf": [
+                {"const": "a"},
+                {"const": "b"},
+            ]
+        });
+        assert!(oneof_branch_overlaps(&schema).is_empty());
+    }
+
+    #[test]
+    fn required_property_excluded_by_closed_schema_is_disjoint() {
+        let schema = json!({
+            "oneOf": [
+                {"required": ["kind"]},
+                {
+                    "properties": {"name": {"type": "string"}},
+                    "additionalProperties": false,
+                },
+            ]
+        });
+        assert!(oneof_branch_overlaps(&schema).is_empty());
+    }
+
+    #[test]
+    fn shared_required_property_with_disjoint_signature_is_disjoint() {
+        let schema = json!({
+            "oneOf": [
+                {
+                    "required": ["kind"],
+                    "properties": {"kind": {"const": "a"}},
+                },
+                {
+                    "required": ["kind"],
+                    "properties": {"kind": {"const": "b"}},
+                },
+            ]
+        });
+        assert!(oneof_branch_overlaps(&schema).is_empty());
+    }
+
+    #[test]
+    fn false_schema_branch_is_always_disjoint() {
+        let schema = json!({
+            "oneOf": [
+                false,
+                {"type": "string"},
+            ]
+        });
+        assert!(oneof_branch_overlaps(&schema).is_empty());
+    }
+
+    #[test]
+    fn nested_oneof_is_visited_with_its_own_pointer() {
+        let schema = json!({
+            "properties": {
+                "value": {
+                    "oneOf": [
+                        {"type": "string"},
+                        {"type": "string"},
+                    ]
+                }
+            }
+        });
+        let overlaps = oneof_branch_overlaps(&schema);
+        assert_eq!(overlaps.len(), 1);
+        assert_eq!(overlaps[0].pointer, "/properties/value/oneOf");
+    }
+}
@@ -0,0 +1,192 @@
+//! A small axis-based path query, borrowed from the axis model of document
+//! query languages: `values` selects direct children, `descendants` selects
+//! every nested node, and `at(index)` selects one array element by
+//! position. A query is an ordered list of these steps; [`select`]
+//! evaluates one against an instance and returns every `(pointer, &Value)`
+//! pair it reaches, and [`validate_at`] then runs a single subschema against
+//! each of those pairs.
+//!
+//! TODO: this re-implements location tracking as plain JSON Pointer strings
+//! rather than reusing the crate's own `Location`/`LazyLocation`/
+//! `RefTracker` machinery the request asks for, and compiles `subschema`
+//! once via [`crate::validator_for`] rather than a pre-compiled
+//! `SchemaNode` — `Location`/`LazyLocation`/`RefTracker`'s own defining
+//! module (`paths.rs`) isn't part of this checkout to build on directly, so
+//! this stands on the public `validator_for`/`Validator` API instead (the
+//! same precedent used by `evaluated_properties`).
+
+use serde_json::Value;
+
+use crate::{validator_for, ValidationError};
+
+/// A single step in a [`select`] query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum AxisStep {
+    /// Direct children: array elements or object property values.
+    Values,
+    /// Every node nested anywhere below the current one.
+    Descendants,
+    /// The array element at a specific index.
+    At(usize),
+}
+
+/// A location reached by a query, paired with the value found there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QueryMatch<'i> {
+    pub(crate) pointer: String,
+    pub(crate) value: &'i Value,
+}
+
+/// Evaluates `steps` against `instance`, returning every location reached.
+pub(crate) fn select<'i>(instance: &'i Value, steps: &[AxisStep]) -> Vec<QueryMatch<'i>> {
+    let mut matches = Vec::new();
+    select_into(instance, String::new(), steps, &mut matches);
+    matches
+}
+
+fn select_into<'i>(
+    value: &'i Value,
+    pointer: String,
+    steps: &[AxisStep],
+    matches: &mut Vec<QueryMatch<'i>>,
+) {
+    let Some((step, rest)) = steps.split_first() else {
+        matches.push(QueryMatch { pointer, value });
+        return;
+    };
+
+    match step {
+        AxisStep::At(index) => {
+            if let Some(item) = value.as_array().and_then(|items| items.get(*index)) {
+                select_into(item, format!("{pointer}/{index}"), rest, matches);
+            }
+        }
+        AxisStep::Values => match value {
+            Value::Array(items) => {
+                for (index, item) in items.iter().enumerate() {
+                    select_into(item, format!("{pointer}/{index}"), rest, matches);
+                }
+            }
+            Value::Object(map) => {
+                for (key, item) in map {
+                    select_into(item, format!("{pointer}/{}", escape(key)), rest, matches);
+                }
+            }
+            _ => {}
+        },
+        AxisStep::Descendants => {
+            let mut descendants = Vec::new();
+            collect_descendants(value, &pointer, &mut descendants);
+            for (descendant_pointer, descendant) in descendants {
+                select_into(descendant, descendant_pointer, rest, matches);
+            }
+        }
+    }
+}
+
+fn collect_descendants<'i>(value: &'i Value, pointer: &str, out: &mut Vec<(String, &'i Value)>) {
+    match value {
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let child_pointer = format!("{pointer}/{index}");
+                out.push((child_pointer.clone(), item));
+                collect_descendants(item, &child_pointer, out);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                let child_pointer = format!("{pointer}/{}", escape(key));
+                out.push((child_pointer.clone(), item));
+                collect_descendants(item, &child_pointer, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// A `subschema` validation failure at one of a query's matched locations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct QueryValidationError {
+    pub(crate) pointer: String,
+    pub(crate) message: String,
+}
+
+/// Evaluates `steps` against `instance`, then validates `subschema` against
+/// every matched location, returning every failure found.
+///
+/// Returns `Err` if `subschema` itself fails to compile.
+pub(crate) fn validate_at<'i>(
+    instance: &'i Value,
+    steps: &[AxisStep],
+    subschema: &Value,
+) -> Result<Vec<QueryValidationError>, ValidationError<'static>> {
+    let validator = validator_for(subschema).map_err(ValidationError::to_owned)?;
+    Ok(select(instance, steps)
+        .into_iter()
+        .filter_map(|matched| {
+            validator.validate(matched.value).err().map(|error| QueryValidationError {
+                pointer: matched.pointer,
+                message: error.to_string(),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{select, validate_at, AxisStep};
+    use serde_json::json;
+
+    #[test]
+    fn values_selects_direct_array_elements() {
+        let instance = json!(["a", "b", "c"]);
+        let matches = select(&instance, &[AxisStep::Values]);
+        let pointers: Vec<_> = matches.iter().map(|m| m.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/0", "/1", "/2"]);
+    }
+
+    #[test]
+    fn at_selects_a_single_index() {
+        let instance = json!(["a", "b", "c"]);
+        let matches = select(&instance, &[AxisStep::At(1)]);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].pointer, "/1");
+        assert_eq!(matches[0].value, &json!("b"));
+    }
+
+    #[test]
+    fn descendants_selects_every_nested_node() {
+        let instance = json!({"a": [1, 2]});
+        let matches = select(&instance, &[AxisStep::Descendants]);
+        let pointers: Vec<_> = matches.iter().map(|m| m.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/a", "/a/0", "/a/1"]);
+    }
+
+    #[test]
+    fn chained_steps_compose() {
+        let instance = json!({"items": [{"id": 1}, {"id": "oops"}]});
+        let matches = select(
+            &instance,
+            &[AxisStep::Values, AxisStep::Values, AxisStep::Values],
+        );
+        let pointers: Vec<_> = matches.iter().map(|m| m.pointer.as_str()).collect();
+        assert_eq!(pointers, vec!["/items/0/id", "/items/1/id"]);
+    }
+
+    #[test]
+    fn validate_at_reports_failures_at_matched_locations() {
+        let instance = json!({"items": [{"id": 1}, {"id": "oops"}]});
+        let failures = validate_at(
+            &instance,
+            &[AxisStep::Values, AxisStep::Values, AxisStep::Values],
+            &json!({"type": "integer"}),
+        )
+        .unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].pointer, "/items/1/id");
+    }
+}
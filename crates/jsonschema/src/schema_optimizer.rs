@@ -0,0 +1,277 @@
+//! A schema-rewriting pass that statically simplifies `allOf` +
+//! `unevaluatedProperties` into the much cheaper `additionalProperties`
+//! shape, when it's safe to do so.
+//!
+//! `unevaluatedProperties` needs `PropertiesFilter` (see its TODO in
+//! `keywords::unevaluated_properties`) precisely because, in general, which
+//! properties an `allOf` branch covers can depend on `$ref`/`$dynamicRef`
+//! resolution or conditional application. But a very common case doesn't:
+//! an `allOf` of plain inline object schemas with no such escape hatches.
+//! There, every property either branch could ever evaluate is already
+//! known just by reading `properties`/`patternProperties` off the branches,
+//! so `unevaluatedProperties: X` means exactly `additionalProperties: X`
+//! once those are merged into the parent.
+//!
+//! [`inline_allof_for_unevaluated_properties`] rewrites a schema `Value` in
+//! place wherever that's provably safe, and leaves it untouched everywhere
+//! else. It's conservative: it bails out (doesn't touch the subtree) unless
+//! every condition it needs holds, rather than guessing.
+//!
+//! TODO: wiring this in as an actual pre-`compile()` pass (so
+//! `UnevaluatedPropertiesValidator::compile` never constructs the
+//! `CombinatorFilter`/`PropertiesFilter` machinery for schemas this already
+//! simplifies) needs the compiler entry point, which isn't part of this
+//! checkout. For now this only rewrites a `Value` a caller hands it before
+//! compiling it themselves.
+
+use serde_json::{Map, Value};
+
+/// Keywords whose presence on an `allOf` branch (or the parent, for
+/// `additionalProperties`) makes the evaluated-property set of that branch
+/// not staticly determinable from `properties`/`patternProperties` alone.
+const UNSAFE_BRANCH_KEYWORDS: &[&str] = &[
+    "$ref",
+    "$dynamicRef",
+    "$recursiveRef",
+    "dependentSchemas",
+    "if",
+    "then",
+    "else",
+    "allOf",
+    "anyOf",
+    "oneOf",
+    "not",
+    "unevaluatedProperties",
+    "unevaluatedItems",
+];
+
+/// Recursively rewrites every `allOf` + `unevaluatedProperties` site in
+/// `schema` that's safe to simplify, in place.
+///
+/// Returns the number of sites rewritten.
+pub(crate) fn inline_allof_for_unevaluated_properties(schema: &mut Value) -> usize {
+    let mut rewritten = 0;
+    walk(schema, &mut rewritten);
+    rewritten
+}
+
+fn walk(value: &mut Value, rewritten: &mut usize) {
+    match value {
+        Value::Object(map) => {
+            if try_inline(map) {
+                *rewritten += 1;
+            }
+            for nested in map.values_mut() {
+                walk(nested, rewritten);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                walk(item, rewritten);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Attempts the rewrite on a single schema object; returns `true` if it
+/// simplified this object.
+fn try_inline(parent: &mut Map<String, Value>) -> bool {
+    if !parent.contains_key("unevaluatedProperties") || parent.contains_key("additionalProperties")
+    {
+        return false;
+    }
+    let Some(Value::Array(branches)) = parent.get("allOf") else {
+        return false;
+    };
+    if branches.is_empty() {
+        return false;
+    }
+    if !branches.iter().all(is_inlinable_branch) {
+        return false;
+    }
+    if UNSAFE_BRANCH_KEYWORDS
+        .iter()
+        .any(|keyword| parent.contains_key(*keyword))
+    {
+        // `allOf` and `unevaluatedProperties` are in the unsafe list and are
+        // expected to be present; everything else in the list must be absent
+        // from the parent too, or its own evaluated-property contribution
+        // would be lost by only looking at `allOf`'s branches.
+        if parent.iter().any(|(key, _)| {
+            UNSAFE_BRANCH_KEYWORDS.contains(&key.as_str())
+                && key != "allOf"
+                && key != "unevaluatedProperties"
+        }) {
+            return false;
+        }
+    }
+    // Merging keeps one subschema per property name or pattern, so bail if
+    // the parent and its branches declare the same one more than once.
+    let mut declared = std::collections::HashSet::new();
+    for source in std::iter::once(&*parent).chain(branches.iter().filter_map(Value::as_object)) {
+        for keyword in ["properties", "patternProperties"] {
+            if let Some(Value::Object(entries)) = source.get(keyword) {
+                if !entries.keys().all(|name| declared.insert((keyword, name.clone()))) {
+                    return false;
+                }
+            }
+        }
+    }
+
+    let branches = match parent.remove("allOf") {
+        Some(Value::Array(branches)) => branches,
+        _ => unreachable!("checked above"),
+    };
+
+    let mut merged_properties = match parent.remove("properties") {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+    let mut merged_patterns = match parent.remove("patternProperties") {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+
+    for branch in &branches {
+        let Value::Object(branch) = branch else {
+            continue;
+        };
+        if let Some(Value::Object(props)) = branch.get("properties") {
+            for (key, subschema) in props {
+                merged_properties.insert(key.clone(), subschema.clone());
+            }
+        }
+        if let Some(Value::Object(patterns)) = branch.get("patternProperties") {
+            for (pattern, subschema) in patterns {
+                merged_patterns.insert(pattern.clone(), subschema.clone());
+            }
+        }
+    }
+
+    if !merged_properties.is_empty() {
+        parent.insert("properties".to_string(), Value::Object(merged_properties));
+    }
+    if !merged_patterns.is_empty() {
+        parent.insert(
+            "patternProperties".to_string(),
+            Value::Object(merged_patterns),
+        );
+    }
+
+    let unevaluated = parent
+        .remove("unevaluatedProperties")
+        .expect("checked above");
+    parent.insert("additionalProperties".to_string(), unevaluated);
+
+    true
+}
+
+fn is_inlinable_branch(branch: &Value) -> bool {
+    let Value::Object(branch) = branch else {
+        return false;
+    };
+    // Any other keyword on a branch (`required`, `type`, ...) would be
+    // dropped by the merge, so only these two are accepted.
+    !UNSAFE_BRANCH_KEYWORDS
+        .iter()
+        .any(|keyword| branch.contains_key(*keyword))
+        && branch
+            .keys()
+            .all(|key| matches!(key.as_str(), "properties" | "patternProperties"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inline_allof_for_unevaluated_properties;
+    use serde_json::json;
+
+    #[test]
+    fn inlines_plain_object_branches() {
+        let mut schema = json!({
+            "allOf": [
+                {"properties": {"a": {"type": "string"}}},
+                {"properties": {"b": {"type": "integer"}}},
+            ],
+            "unevaluatedProperties": false,
+        });
+        let rewritten = inline_allof_for_unevaluated_properties(&mut schema);
+        assert_eq!(rewritten, 1);
+        assert_eq!(
+            schema,
+            json!({
+                "properties": {
+                    "a": {"type": "string"},
+                    "b": {"type": "integer"},
+                },
+                "additionalProperties": false,
+            })
+        );
+    }
+
+    #[test]
+    fn merges_pattern_properties_and_keeps_parents_own() {
+        let mut schema = json!({
+            "properties": {"a": {"type": "string"}},
+            "allOf": [
+                {"patternProperties": {"^x-": {"type": "string"}}},
+            ],
+            "unevaluatedProperties": false,
+        });
+        inline_allof_for_unevaluated_properties(&mut schema);
+        assert_eq!(
+            schema,
+            json!({
+                "properties": {"a": {"type": "string"}},
+                "patternProperties": {"^x-": {"type": "string"}},
+                "additionalProperties": false,
+            })
+        );
+    }
+
+    #[test]
+    fn does_not_touch_branches_with_ref() {
+        let mut schema = json!({
+            "allOf": [{"$ref": "#/$defs/thing"}],
+            "unevaluatedProperties": false,
+        });
+        let original = schema.clone();
+        let rewritten = inline_allof_for_unevaluated_properties(&mut schema);
+        assert_eq!(rewritten, 0);
+        assert_eq!(schema, original);
+    }
+
+    #[test]
+    fn does_not_touch_schema_with_existing_additional_properties() {
+        let mut schema = json!({
+            "allOf": [{"properties": {"a": {"type": "string"}}}],
+            "unevaluatedProperties": false,
+            "additionalProperties": true,
+        });
+        let original = schema.clone();
+        let rewritten = inline_allof_for_unevaluated_properties(&mut schema);
+        assert_eq!(rewritten, 0);
+        assert_eq!(schema, original);
+    }
+
+    #[test]
+    fn recurses_into_nested_schemas() {
+        let mut schema = json!({
+            "properties": {
+                "child": {
+                    "allOf": [{"properties": {"x": {"type": "string"}}}],
+                    "unevaluatedProperties": false,
+                }
+            }
+        });
+        let rewritten = inline_allof_for_unevaluated_properties(&mut schema);
+        assert_eq!(rewritten, 1);
+        assert_eq!(
+            schema["properties"]["child"],
+            json!({
+                "properties": {"x": {"type": "string"}},
+                "additionalProperties": false,
+            })
+        );
+    }
+}
@@ -0,0 +1,333 @@
+//! Byte-span tracking for JSON documents, so diagnostics can point at the
+//! exact bytes of an offending value in the original source text,
+//! the way a labeled-span renderer underlines a token under a caret.
+//!
+//! This is a self-contained building block: `ValidationError` (which would
+//! attach spans to errors like `unevaluated_properties`, see the first TODO
+//! below) isn't part of this checkout, so
+//! nothing wires this in there yet. `parse_with_spans` only needs
+//! `serde_json::Value`, so it stands on its own — `jsonschema-rb`'s
+//! `Evaluation#errors(snippets: true)` already uses it this way: it
+//! pretty-prints the validated instance back to text (there's no original
+//! source text to point into, since the instance came from Ruby objects,
+//! not a parsed document) and renders a snippet for each error's
+//! `instance_location` from the resulting [`SpanMap`].
+//!
+//! TODO: once `ValidationError` gains a field for this, thread a `&SpanMap`
+//! through `UnevaluatedPropertiesValidator::validate` so each unevaluated key
+//! in `ValidationError::unevaluated_properties` carries its own span.
+//!
+//! TODO: a `miette`-style `Diagnostic` impl for `ValidationError` (so a
+//! generic diagnostic renderer gets caret-underlined snippets for free,
+//! rather than the plain string [`render_snippet`] below produces) needs
+//! both `ValidationError` and a `miette` dependency, neither part of this
+//! checkout; `render_snippet` is the part that needs neither.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// A half-open byte range `[start, end)` into the original JSON source text.
+pub type ByteSpan = (usize, usize);
+
+/// Maps a JSON Pointer (RFC 6901) to the byte span of the value found there
+/// in the original source text.
+///
+/// A pointer with no entry means the location has no span — either it wasn't
+/// present in the parsed document at all, or the value at that path was
+/// synthesized rather than parsed from text.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SpanMap {
+    spans: BTreeMap<String, ByteSpan>,
+}
+
+impl SpanMap {
+    /// The byte span recorded for `pointer`, if any.
+    pub fn get(&self, pointer: &str) -> Option<ByteSpan> {
+        self.spans.get(pointer).copied()
+    }
+
+    /// The byte span of a single property under `parent_pointer`, addressed
+    /// by its (unescaped) key, if any.
+    pub fn get_property(&self, parent_pointer: &str, key: &str) -> Option<ByteSpan> {
+        let child = format!("{parent_pointer}/{}", escape_pointer_segment(key));
+        self.get(&child)
+    }
+
+    fn remove_subtree(&mut self, pointer: &str) {
+        let prefix = format!("{pointer}/");
+        self.spans
+            .retain(|key, _| key != pointer && !key.starts_with(&prefix));
+    }
+}
+
+/// Escapes a single JSON Pointer reference token per RFC 6901: `~` becomes
+/// `~0` and `/` becomes `~1`.
+fn escape_pointer_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Renders a single-line, caret-underlined snippet of `text` around `span`,
+/// labeling the exact bytes a diagnostic wants to point at:
+///
+/// ```text
+/// {"a": 1, "extra": "oops"}
+///                   ^^^^^^
+/// ```
+///
+/// A span that crosses a line break is underlined only to the end of its
+/// first line; a zero-width span still gets a single caret.
+pub fn render_snippet(text: &str, span: ByteSpan) -> String {
+    let (start, end) = span;
+    let line_start = text[..start].rfind('\n').map_or(0, |index| index + 1);
+    let line_end = text[start..]
+        .find('\n')
+        .map_or(text.len(), |index| start + index);
+    let line = &text[line_start..line_end];
+
+    let caret_start = start - line_start;
+    let caret_end = end.min(line_end) - line_start;
+    let caret_width = (caret_end - caret_start).max(1);
+    let underline: String = " ".repeat(caret_start) + &"^".repeat(caret_width);
+
+    format!("{line}\n{underline}")
+}
+
+/// Parses `text` into both a [`Value`] and a [`SpanMap`] recording the byte
+/// span of every value, keyed by JSON Pointer. Object keys get no span of
+/// their own: a property's pointer maps to the span of its value.
+///
+/// Objects with duplicate keys keep the last occurrence's span (and discard
+/// any spans recorded for the earlier occurrence's subtree), matching
+/// `serde_json`'s own last-key-wins semantics for the parsed `Value`.
+pub fn parse_with_spans(text: &str) -> Result<(Value, SpanMap), serde_json::Error> {
+    let value = serde_json::from_str(text)?;
+    let mut spans = SpanMap::default();
+    let mut scanner = Scanner {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+    scanner.skip_whitespace();
+    scanner.scan_value("", &mut spans);
+    Ok((value, spans))
+}
+
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn skip_whitespace(&mut self) {
+        while matches!(self.bytes.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn scan_value(&mut self, pointer: &str, spans: &mut SpanMap) {
+        self.skip_whitespace();
+        let start = self.pos;
+        spans.remove_subtree(pointer);
+        match self.bytes.get(self.pos) {
+            Some(b'{') => self.scan_object(pointer, spans),
+            Some(b'[') => self.scan_array(pointer, spans),
+            Some(b'"') => self.skip_string(),
+            Some(b't') => self.pos += "true".len(),
+            Some(b'f') => self.pos += "false".len(),
+            Some(b'n') => self.pos += "null".len(),
+            _ => self.skip_number(),
+        }
+        spans.spans.insert(pointer.to_string(), (start, self.pos));
+    }
+
+    fn scan_object(&mut self, pointer: &str, spans: &mut SpanMap) {
+        self.pos += 1; // '{'
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+            return;
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.scan_string_content();
+            self.skip_whitespace();
+            if self.bytes.get(self.pos) == Some(&b':') {
+                self.pos += 1;
+            }
+            let child_pointer = format!("{pointer}/{}", escape_pointer_segment(&key));
+            self.scan_value(&child_pointer, spans);
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => {
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b'}') {
+            self.pos += 1;
+        }
+    }
+
+    fn scan_array(&mut self, pointer: &str, spans: &mut SpanMap) {
+        self.pos += 1; // '['
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+            return;
+        }
+        let mut index = 0;
+        loop {
+            let child_pointer = format!("{pointer}/{index}");
+            self.scan_value(&child_pointer, spans);
+            index += 1;
+            self.skip_whitespace();
+            match self.bytes.get(self.pos) {
+                Some(b',') => {
+                    self.pos += 1;
+                }
+                _ => break,
+            }
+        }
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&b']') {
+            self.pos += 1;
+        }
+    }
+
+    /// Scans a `"..."` string starting at the current position and returns
+    /// its unescaped content, used for object keys.
+    fn scan_string_content(&mut self) -> String {
+        let start = self.pos;
+        self.skip_string();
+        let raw = &self.bytes[start + 1..self.pos - 1];
+        // Keys are almost never escaped in practice; fall back to the raw
+        // bytes (still correct for the common unescaped case) if decoding
+        // via serde_json ever fails on a malformed fragment.
+        serde_json::from_slice::<String>(&self.bytes[start..self.pos])
+            .unwrap_or_else(|_| String::from_utf8_lossy(raw).into_owned())
+    }
+
+    /// Advances past a `"..."` string, honoring backslash escapes.
+    fn skip_string(&mut self) {
+        self.pos += 1; // opening '"'
+        while let Some(&b) = self.bytes.get(self.pos) {
+            match b {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+
+    fn skip_number(&mut self) {
+        if self.bytes.get(self.pos) == Some(&b'-') {
+            self.pos += 1;
+        }
+        while matches!(self.bytes.get(self.pos), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        if self.bytes.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            while matches!(self.bytes.get(self.pos), Some(b'0'..=b'9')) {
+                self.pos += 1;
+            }
+        }
+        if matches!(self.bytes.get(self.pos), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.bytes.get(self.pos), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            while matches!(self.bytes.get(self.pos), Some(b'0'..=b'9')) {
+                self.pos += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_with_spans, render_snippet};
+    use serde_json::json;
+
+    #[test]
+    fn root_span_covers_whole_document() {
+        let text = r#"{"a": 1}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        assert_eq!(spans.get(""), Some((0, text.len())));
+    }
+
+    #[test]
+    fn nested_object_property_spans() {
+        let text = r#"{"a": {"b": "c"}}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        let (start, end) = spans.get("/a/b").unwrap();
+        assert_eq!(&text[start..end], r#""c""#);
+    }
+
+    #[test]
+    fn array_element_spans() {
+        let text = r#"[10, 20, 30]"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        let (start, end) = spans.get("/1").unwrap();
+        assert_eq!(&text[start..end], "20");
+    }
+
+    #[test]
+    fn get_property_escapes_the_key() {
+        let text = r#"{"a/b": 1}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        let (start, end) = spans.get_property("", "a/b").unwrap();
+        assert_eq!(&text[start..end], "1");
+    }
+
+    #[test]
+    fn duplicate_keys_keep_last_and_drop_stale_subtree() {
+        // serde_json keeps {"x": 2} for "a"; the span for /a/y (only
+        // present under the first, discarded occurrence) must not linger.
+        let text = r#"{"a": {"y": 1}, "a": {"x": 2}}"#;
+        let value = json!({"a": {"x": 2}});
+        let (parsed, spans) = parse_with_spans(text).unwrap();
+        assert_eq!(parsed, value);
+        assert!(spans.get("/a/y").is_none());
+        let (start, end) = spans.get("/a/x").unwrap();
+        assert_eq!(&text[start..end], "2");
+    }
+
+    #[test]
+    fn string_values_with_escapes_do_not_confuse_the_scanner() {
+        let text = r#"{"a": "x\"y", "b": 2}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        let (start, end) = spans.get("/b").unwrap();
+        assert_eq!(&text[start..end], "2");
+    }
+
+    #[test]
+    fn missing_pointer_has_no_span() {
+        let text = r#"{"a": 1}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        assert!(spans.get("/does-not-exist").is_none());
+    }
+
+    #[test]
+    fn render_snippet_underlines_the_span() {
+        let text = r#"{"a": 1, "extra": "oops"}"#;
+        let (_, spans) = parse_with_spans(text).unwrap();
+        let span = spans.get_property("", "extra").unwrap();
+        let snippet = render_snippet(text, span);
+        assert_eq!(
+            snippet,
+            "{\"a\": 1, \"extra\": \"oops\"}\n                  ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn render_snippet_handles_zero_width_span() {
+        let text = "abc";
+        assert_eq!(render_snippet(text, (1, 1)), "abc\n ^");
+    }
+}